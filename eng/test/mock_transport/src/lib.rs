@@ -1,9 +1,11 @@
+mod mock_http_client;
 mod mock_request;
 mod mock_response;
 mod mock_transaction;
 mod player_policy;
 mod recorder_policy;
 
+pub use mock_http_client::MockHttpClient;
 use mock_transaction::MockTransaction;
 use player_policy::MockTransportPlayerPolicy;
 use recorder_policy::MockTransportRecorderPolicy;
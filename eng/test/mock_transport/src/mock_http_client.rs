@@ -0,0 +1,151 @@
+use azure_core::{
+    error::{Error, ErrorKind},
+    headers::Headers,
+    BytesStream, HttpClient, Method, Request, Response, StatusCode,
+};
+use bytes::Bytes;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex, PoisonError,
+};
+
+/// An `HttpClient` that answers requests with canned responses instead of
+/// going to the network.
+///
+/// A response is matched on the request method and path. If the registered
+/// path contains a query string, the query has to match as well.
+///
+/// Every request is recorded and can be inspected with `requests`.
+#[derive(Debug, Default)]
+pub struct MockHttpClient {
+    responses: Vec<CannedResponse>,
+    requests: Mutex<Vec<Request>>,
+}
+
+#[derive(Debug)]
+struct CannedResponse {
+    method: Method,
+    path: String,
+    status: StatusCode,
+    headers: Headers,
+    body: Bytes,
+    /// Answer only once, failing the body after this many bytes.
+    interrupt_after: Option<usize>,
+    used: AtomicBool,
+}
+
+impl MockHttpClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `method` requests to `path` with the given response.
+    #[must_use]
+    pub fn with_response(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        status: StatusCode,
+        headers: Headers,
+        body: impl Into<Bytes>,
+    ) -> Self {
+        self.responses.push(CannedResponse {
+            method,
+            path: path.into(),
+            status,
+            headers,
+            body: body.into(),
+            interrupt_after: None,
+            used: AtomicBool::new(false),
+        });
+        self
+    }
+
+    /// Answer the next `method` request to `path` with a response whose body
+    /// fails after `interrupt_after` bytes, as if the connection dropped.
+    ///
+    /// The response is only used once; later requests fall through to the
+    /// responses registered after it.
+    #[must_use]
+    pub fn with_interrupted_response(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        status: StatusCode,
+        headers: Headers,
+        body: impl Into<Bytes>,
+        interrupt_after: usize,
+    ) -> Self {
+        self.responses.push(CannedResponse {
+            method,
+            path: path.into(),
+            status,
+            headers,
+            body: body.into(),
+            interrupt_after: Some(interrupt_after),
+            used: AtomicBool::new(false),
+        });
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl HttpClient for MockHttpClient {
+    async fn execute_request(&self, request: &Request) -> azure_core::Result<Response> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(request.clone());
+
+        let path_and_query = request.path_and_query();
+        let response = self
+            .responses
+            .iter()
+            .find(|response| {
+                response.method == *request.method()
+                    && if response.path.contains('?') {
+                        response.path == path_and_query
+                    } else {
+                        response.path == request.url().path()
+                    }
+                    && !(response.interrupt_after.is_some()
+                        && response.used.swap(true, Ordering::SeqCst))
+            })
+            .ok_or_else(|| {
+                Error::with_message(ErrorKind::MockFramework, || {
+                    format!(
+                        "no canned response for {} {path_and_query}",
+                        request.method().as_ref()
+                    )
+                })
+            })?;
+
+        let status = response.status;
+        let headers = response.headers.clone();
+        Ok(match response.interrupt_after {
+            Some(interrupt_after) => {
+                let sent = response
+                    .body
+                    .slice(..interrupt_after.min(response.body.len()));
+                let chunks = vec![
+                    Ok(sent),
+                    Err(Error::message(ErrorKind::Io, "connection interrupted")),
+                ];
+                Response::new(status, headers, Box::pin(futures::stream::iter(chunks)))
+            }
+            None => {
+                let body: BytesStream = response.body.clone().into();
+                Response::new(status, headers, Box::pin(body))
+            }
+        })
+    }
+}
@@ -51,7 +51,9 @@ pub trait Header {
 }
 
 /// A collection of headers
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+///
+/// The `Debug` output redacts the value of the `authorization` header.
+#[derive(Clone, PartialEq, Eq, Default)]
 pub struct Headers(std::collections::HashMap<HeaderName, HeaderValue>);
 
 impl Headers {
@@ -151,6 +153,31 @@ impl Headers {
     }
 }
 
+/// Headers whose values are secrets, and are never printed.
+const REDACTED_HEADERS: &[HeaderName] = &[AUTHORIZATION, COPY_SOURCE_AUTHORIZATION, ENCRYPTION_KEY];
+
+impl Debug for Headers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Headers")?;
+        f.debug_map()
+            .entries(self.0.iter().map(|(name, value)| {
+                let value: std::borrow::Cow<str> = if REDACTED_HEADERS.contains(name) {
+                    "<REDACTED>".into()
+                } else {
+                    // URL-valued headers, such as a copy source, may carry a SAS token
+                    match crate::Url::parse(value.as_str()) {
+                        Ok(url) if matches!(url.scheme(), "http" | "https") => {
+                            crate::util::redact_signature(&url).to_string().into()
+                        }
+                        _ => value.as_str().into(),
+                    }
+                };
+                (name.as_str(), value)
+            }))
+            .finish()
+    }
+}
+
 impl IntoIterator for Headers {
     type Item = (HeaderName, HeaderValue);
 
@@ -294,6 +321,8 @@ pub const CONTINUATION: HeaderName = HeaderName::from_static("x-ms-continuation"
 pub const COPY_COMPLETION_TIME: HeaderName = HeaderName::from_static("x-ms-copy-completion-time");
 pub const COPY_PROGRESS: HeaderName = HeaderName::from_static("x-ms-copy-progress");
 pub const COPY_SOURCE: HeaderName = HeaderName::from_static("x-ms-copy-source");
+pub const COPY_SOURCE_AUTHORIZATION: HeaderName =
+    HeaderName::from_static("x-ms-copy-source-authorization");
 pub const COPY_STATUS: HeaderName = HeaderName::from_static("x-ms-copy-status");
 pub const COPY_STATUS_DESCRIPTION: HeaderName =
     HeaderName::from_static("x-ms-copy-status-description");
@@ -372,3 +401,55 @@ pub const BLOB_COMMITTED_BLOCK_COUNT: HeaderName =
 pub const AZURE_ASYNCOPERATION: HeaderName = HeaderName::from_static("azure-asyncoperation");
 pub const OPERATION_LOCATION: HeaderName = HeaderName::from_static("operation-location");
 pub const SOURCE_RANGE: HeaderName = HeaderName::from_static("x-ms-source-range");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_redacts_authorization() {
+        let mut headers = Headers::new();
+        headers.insert(AUTHORIZATION, "SharedKey account:signature");
+        headers.insert(VERSION, "2022-11-02");
+
+        let debug = format!("{headers:?}");
+        assert!(!debug.contains("signature"));
+        assert!(debug.contains("<REDACTED>"));
+        assert!(debug.contains("2022-11-02"));
+    }
+
+    #[test]
+    fn debug_redacts_encryption_key() {
+        let mut headers = Headers::new();
+        headers.insert(ENCRYPTION_KEY, "c2VjcmV0LWtleQ==");
+        headers.insert(ENCRYPTION_KEY_SHA256, "hash");
+
+        let debug = format!("{headers:?}");
+        assert!(!debug.contains("c2VjcmV0LWtleQ=="));
+        assert!(debug.contains("hash"));
+    }
+
+    #[test]
+    fn debug_redacts_copy_source_authorization() {
+        let mut headers = Headers::new();
+        headers.insert(COPY_SOURCE_AUTHORIZATION, "Bearer token-value");
+
+        let debug = format!("{headers:?}");
+        assert!(!debug.contains("token-value"));
+        assert!(debug.contains("<REDACTED>"));
+    }
+
+    #[test]
+    fn debug_redacts_copy_source_signature() {
+        let mut headers = Headers::new();
+        headers.insert(
+            COPY_SOURCE,
+            "https://a.blob.core.windows.net/c/b?sv=2022-11-02&sig=secret",
+        );
+
+        let debug = format!("{headers:?}");
+        assert!(!debug.contains("secret"));
+        assert!(debug.contains("sig=REDACTED"));
+        assert!(debug.contains("sv=2022-11-02"));
+    }
+}
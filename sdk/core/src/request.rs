@@ -63,7 +63,7 @@ impl From<Box<dyn SeekableStream>> for Body {
 ///
 /// A pipeline request is composed by a destination (uri), a method, a collection of headers and a
 /// body. Policies are expected to enrich the request by mutating it.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Request {
     pub(crate) url: Url,
     pub(crate) method: Method,
@@ -71,6 +71,17 @@ pub struct Request {
     pub(crate) body: Body,
 }
 
+impl Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("url", &crate::util::redact_signature(&self.url))
+            .field("method", &self.method)
+            .field("headers", &self.headers)
+            .field("body", &self.body)
+            .finish()
+    }
+}
+
 impl Request {
     /// Create a new request with an empty body and no headers
     pub fn new(url: Url, method: Method) -> Self {
@@ -147,3 +158,19 @@ impl Request {
         self.insert_header(item.name(), item.value());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_redacts_signature() {
+        let url =
+            Url::parse("https://a.blob.core.windows.net/c/b?sv=2022-11-02&sig=secret").unwrap();
+        let request = Request::new(url, Method::Get);
+
+        let debug = format!("{request:?}");
+        assert!(!debug.contains("secret"));
+        assert!(debug.contains("sig=REDACTED"));
+    }
+}
@@ -1,5 +1,6 @@
 //! An assortment of helper utilities.
 
+use crate::Url;
 use serde::{
     de::{self, DeserializeOwned, Deserializer},
     Deserialize,
@@ -27,6 +28,20 @@ where
     Ok(opt.unwrap_or_default())
 }
 
+/// Returns a copy of `url` with a SAS signature (`sig`) replaced by `REDACTED`, so the
+/// URL can be logged without leaking the token.
+pub fn redact_signature(url: &Url) -> Url {
+    let mut redacted = url.clone();
+    if url.query_pairs().any(|(k, _)| k == "sig") {
+        let pairs = url.query_pairs().map(|(k, v)| {
+            let v = if k == "sig" { "REDACTED".into() } else { v };
+            (k, v)
+        });
+        redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    redacted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,11 +14,23 @@ const STORAGE_TOKEN_SCOPE: &str = "https://storage.azure.com/.default";
 #[derive(Debug, Clone)]
 pub struct AuthorizationPolicy {
     credentials: StorageCredentials,
+    token_scope: Cow<'static, str>,
 }
 
 impl AuthorizationPolicy {
     pub(crate) fn new(credentials: StorageCredentials) -> Self {
-        Self { credentials }
+        Self {
+            credentials,
+            token_scope: Cow::Borrowed(STORAGE_TOKEN_SCOPE),
+        }
+    }
+
+    /// Request bearer tokens for `token_scope` instead of the default storage scope.
+    pub(crate) fn with_token_scope(credentials: StorageCredentials, token_scope: String) -> Self {
+        Self {
+            credentials,
+            token_scope: Cow::Owned(token_scope),
+        }
     }
 }
 
@@ -38,56 +50,90 @@ impl Policy for AuthorizationPolicy {
             "Authorization policies cannot be the last policy of a pipeline"
         );
 
-        // lock the credentials within a scope so that it is released as soon as possible
-        {
-            let creds = self.credentials.0.read().await;
-
-            match creds.deref() {
-                StorageCredentialsInner::Key(account, key) => {
-                    if !request.url().query_pairs().any(|(k, _)| &*k == "sig") {
-                        let auth = generate_authorization(
-                            request.headers(),
-                            request.url(),
-                            *request.method(),
-                            account,
-                            key,
-                            *ctx.get()
-                                .expect("ServiceType must be in the Context at this point"),
-                        )?;
-                        request.insert_header(AUTHORIZATION, auth);
-                    }
-                }
-                StorageCredentialsInner::SASToken(query_pairs) => {
-                    // Ensure the signature param is not already present
-                    if !request.url().query_pairs().any(|(k, _)| &*k == "sig") {
-                        request
-                            .url_mut()
-                            .query_pairs_mut()
-                            .extend_pairs(query_pairs);
-                    }
-                }
-                StorageCredentialsInner::BearerToken(token) => {
-                    request.insert_header(AUTHORIZATION, format!("Bearer {}", token.secret()));
-                }
-                StorageCredentialsInner::TokenCredential(token_credential) => {
-                    let bearer_token = token_credential
-                        .get_token(&[STORAGE_TOKEN_SCOPE])
-                        .await
-                        .context(ErrorKind::Credential, "failed to get bearer token")?;
-
-                    request.insert_header(
-                        AUTHORIZATION,
-                        format!("Bearer {}", bearer_token.token.secret()),
-                    );
-                }
-                StorageCredentialsInner::Anonymous => {}
-            }
-        };
+        authorize(
+            &self.credentials,
+            &self.token_scope,
+            ctx.get().copied(),
+            request,
+        )
+        .await?;
 
         next[0].send(ctx, request, &next[1..]).await
     }
 }
 
+/// Add the authorization for `credentials` to a request that is not sent through
+/// a storage pipeline, such as a sub-request of a batch.
+///
+/// `token_scope` is the scope requested for bearer tokens, and should match the
+/// one used by the client's pipeline. `None` uses the default storage scope.
+pub async fn authorize_request(
+    credentials: &StorageCredentials,
+    token_scope: Option<&str>,
+    service_type: ServiceType,
+    request: &mut Request,
+) -> azure_core::Result<()> {
+    authorize(
+        credentials,
+        token_scope.unwrap_or(STORAGE_TOKEN_SCOPE),
+        Some(service_type),
+        request,
+    )
+    .await
+}
+
+async fn authorize(
+    credentials: &StorageCredentials,
+    token_scope: &str,
+    service_type: Option<ServiceType>,
+    request: &mut Request,
+) -> azure_core::Result<()> {
+    // lock the credentials within a scope so that it is released as soon as possible
+    let creds = credentials.0.read().await;
+
+    match creds.deref() {
+        StorageCredentialsInner::Key(account, key) => {
+            if !request.url().query_pairs().any(|(k, _)| &*k == "sig") {
+                let auth = generate_authorization(
+                    request.headers(),
+                    request.url(),
+                    *request.method(),
+                    account,
+                    key,
+                    service_type.expect("ServiceType must be in the Context at this point"),
+                )?;
+                request.insert_header(AUTHORIZATION, auth);
+            }
+        }
+        StorageCredentialsInner::SASToken(query_pairs) => {
+            // Ensure the signature param is not already present
+            if !request.url().query_pairs().any(|(k, _)| &*k == "sig") {
+                request
+                    .url_mut()
+                    .query_pairs_mut()
+                    .extend_pairs(query_pairs);
+            }
+        }
+        StorageCredentialsInner::BearerToken(token) => {
+            request.insert_header(AUTHORIZATION, format!("Bearer {}", token.secret()));
+        }
+        StorageCredentialsInner::TokenCredential(token_credential) => {
+            let bearer_token = token_credential
+                .get_token(&[token_scope])
+                .await
+                .context(ErrorKind::Credential, "failed to get bearer token")?;
+
+            request.insert_header(
+                AUTHORIZATION,
+                format!("Bearer {}", bearer_token.token.secret()),
+            );
+        }
+        StorageCredentialsInner::Anonymous => {}
+    }
+
+    Ok(())
+}
+
 fn generate_authorization(
     h: &Headers,
     u: &Url,
@@ -231,7 +277,7 @@ fn lexy_sort<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use azure_core::{BytesStream, Response};
+    use azure_core::{auth::AccessToken, BytesStream, Response};
 
     #[derive(Debug, Clone)]
     struct AssertSigHeaderUniqueMockPolicy;
@@ -300,4 +346,44 @@ mod tests {
             .await
             .unwrap();
     }
+
+    /// Hands out the requested scope as the token, so tests can see which scope was asked for.
+    #[derive(Debug)]
+    struct ScopeEchoCredential;
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl azure_core::auth::TokenCredential for ScopeEchoCredential {
+        async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+            Ok(AccessToken::new(
+                scopes.join(" "),
+                time::OffsetDateTime::now_utc() + time::Duration::hours(1),
+            ))
+        }
+
+        async fn clear_cache(&self) -> azure_core::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn authorize_request_uses_token_scope() -> azure_core::Result<()> {
+        let credentials = StorageCredentials::token_credential(Arc::new(ScopeEchoCredential));
+
+        let mut request = Request::new(Url::parse("https://example.com").unwrap(), Method::Get);
+        authorize_request(&credentials, None, ServiceType::Blob, &mut request).await?;
+        assert_eq!(
+            request.headers().get_str(&AUTHORIZATION)?,
+            format!("Bearer {STORAGE_TOKEN_SCOPE}")
+        );
+
+        let scope = "https://storage.azure.us/.default";
+        let mut request = Request::new(Url::parse("https://example.com").unwrap(), Method::Get);
+        authorize_request(&credentials, Some(scope), ServiceType::Blob, &mut request).await?;
+        assert_eq!(
+            request.headers().get_str(&AUTHORIZATION)?,
+            format!("Bearer {scope}")
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 mod authorization_policy;
 
+pub use self::authorization_policy::authorize_request;
 pub(crate) use self::authorization_policy::AuthorizationPolicy;
 use crate::clients::{EMULATOR_ACCOUNT, EMULATOR_ACCOUNT_KEY};
 use async_lock::RwLock;
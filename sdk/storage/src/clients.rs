@@ -98,24 +98,56 @@ pub fn finalize_request(
     Ok(request)
 }
 
+/// Add the headers `finalize_request` sets to a request built by the caller: the
+/// date, the service version and, when the request has a body, its length.
+pub fn add_storage_headers(request: &mut Request) {
+    request.insert_header(MS_DATE, date::to_rfc1123(&OffsetDateTime::now_utc()));
+    request.insert_header(VERSION, AZURE_VERSION);
+    if !request.body().is_empty() {
+        let content_length = request.body().len().to_string();
+        request.insert_header(CONTENT_LENGTH, content_length);
+    }
+}
+
 /// Create a Pipeline from `ClientOptions`
 pub fn new_pipeline_from_options(
     options: ClientOptions,
     credentials: StorageCredentials,
 ) -> Pipeline {
-    let auth_policy: Arc<dyn azure_core::Policy> = Arc::new(AuthorizationPolicy::new(credentials));
+    new_pipeline(options, AuthorizationPolicy::new(credentials))
+}
+
+/// Create a Pipeline from `ClientOptions` that requests bearer tokens for `token_scope`
+///
+/// This is only relevant for clients authenticating with a `TokenCredential`, such as
+/// those targeting sovereign clouds or custom audiences.
+pub fn new_pipeline_from_options_with_token_scope(
+    options: ClientOptions,
+    credentials: StorageCredentials,
+    token_scope: impl Into<String>,
+) -> Pipeline {
+    new_pipeline(
+        options,
+        AuthorizationPolicy::with_token_scope(credentials, token_scope.into()),
+    )
+}
+
+fn new_pipeline(mut options: ClientOptions, auth_policy: AuthorizationPolicy) -> Pipeline {
+    let auth_policy: Arc<dyn azure_core::Policy> = Arc::new(auth_policy);
 
     // The `AuthorizationPolicy` must be the **last** retry policy.
     // Policies can change the url and/or the headers, and the `AuthorizationPolicy`
     // must be able to inspect them or the resulting token will be invalid.
-    let per_retry_policies = vec![
-        Arc::new(options.timeout.clone()) as Arc<dyn azure_core::Policy>,
-        auth_policy,
-    ];
+    // This includes any per-retry policies supplied through `ClientOptions`, which
+    // `Pipeline::new` would otherwise place after it.
+    let mut per_retry_policies =
+        vec![Arc::new(options.timeout.clone()) as Arc<dyn azure_core::Policy>];
+    per_retry_policies.append(options.per_retry_policies_mut());
+    per_retry_policies.push(auth_policy);
 
     Pipeline::new(
-        option_env!("CARGO_PKG_NAME"),
-        option_env!("CARGO_PKG_VERSION"),
+        Some(env!("CARGO_PKG_NAME")),
+        Some(env!("CARGO_PKG_VERSION")),
         options,
         Vec::new(),
         per_retry_policies,
@@ -49,6 +49,35 @@ impl CloudLocation {
         };
         Ok(Url::parse(&url)?)
     }
+
+    /// the base URL of the secondary endpoint of a read-access geo-redundant account
+    ///
+    /// Only the Public and China clouds have a well-known secondary endpoint.
+    pub fn secondary_url(&self, service_type: ServiceType) -> azure_core::Result<Url> {
+        let url = match self {
+            CloudLocation::Public { account, .. } => {
+                format!(
+                    "https://{}-secondary.{}.core.windows.net",
+                    account,
+                    service_type.subdomain()
+                )
+            }
+            CloudLocation::China { account, .. } => {
+                format!(
+                    "https://{}-secondary.{}.core.chinacloudapi.cn",
+                    account,
+                    service_type.subdomain()
+                )
+            }
+            CloudLocation::Custom { .. } | CloudLocation::Emulator { .. } => {
+                return Err(azure_core::Error::message(
+                    azure_core::error::ErrorKind::Other,
+                    "only Public and China cloud locations have a well-known secondary endpoint",
+                ))
+            }
+        };
+        Ok(Url::parse(&url)?)
+    }
 }
 
 impl TryFrom<&Url> for CloudLocation {
@@ -130,4 +159,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_secondary_url() -> azure_core::Result<()> {
+        let public = CloudLocation::Public {
+            account: "test".to_owned(),
+        };
+        assert_eq!(
+            Url::parse("https://test-secondary.blob.core.windows.net")?,
+            public.secondary_url(ServiceType::Blob)?
+        );
+
+        let china = CloudLocation::China {
+            account: "test".to_owned(),
+        };
+        assert_eq!(
+            Url::parse("https://test-secondary.queue.core.chinacloudapi.cn")?,
+            china.secondary_url(ServiceType::Queue)?
+        );
+
+        let emulator = CloudLocation::Emulator {
+            address: "127.0.0.1".to_owned(),
+            port: 10000,
+        };
+        assert!(emulator.secondary_url(ServiceType::Blob).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,150 @@
+//! Helpers that classify the errors returned by the storage services.
+//!
+//! Each helper looks at both the HTTP status and the storage error code of the
+//! response, so callers can branch on what went wrong without comparing raw
+//! status codes.
+
+use azure_core::{error::Error, StatusCode};
+
+const NOT_FOUND_CODES: &[&str] = &[
+    "BlobNotFound",
+    "ContainerNotFound",
+    "FilesystemNotFound",
+    "PathNotFound",
+    "QueueNotFound",
+    "ResourceNotFound",
+    "ShareNotFound",
+    "TableNotFound",
+];
+
+const CONFLICT_CODES: &[&str] = &[
+    "BlobAlreadyExists",
+    "ContainerAlreadyExists",
+    "ContainerBeingDeleted",
+    "FilesystemAlreadyExists",
+    "LeaseAlreadyPresent",
+    "PathAlreadyExists",
+    "QueueAlreadyExists",
+    "QueueBeingDeleted",
+    "ShareAlreadyExists",
+    "TableAlreadyExists",
+    "TableBeingDeleted",
+];
+
+const PRECONDITION_FAILED_CODES: &[&str] = &[
+    "AppendPositionConditionNotMet",
+    "ConditionNotMet",
+    "MaxBlobSizeConditionNotMet",
+    "SequenceNumberConditionNotMet",
+    "SourceConditionNotMet",
+    "TargetConditionNotMet",
+];
+
+const THROTTLED_CODES: &[&str] = &["ServerBusy"];
+
+/// Whether `err` is the service reporting that the resource, such as a
+/// container or blob, does not exist.
+pub fn is_not_found(err: &Error) -> bool {
+    matches_http_error(err, StatusCode::NotFound, NOT_FOUND_CODES)
+}
+
+/// Whether `err` is the service rejecting a request because it conflicts with
+/// the current state of the resource, such as an existing container, an active
+/// lease, or a blob of a different type.
+pub fn is_conflict(err: &Error) -> bool {
+    matches_http_error(err, StatusCode::Conflict, CONFLICT_CODES)
+}
+
+/// Whether `err` is the service rejecting a request because one of its
+/// conditions, such as `if_match` or `if_tags`, was not met.
+pub fn is_precondition_failed(err: &Error) -> bool {
+    matches_http_error(
+        err,
+        StatusCode::PreconditionFailed,
+        PRECONDITION_FAILED_CODES,
+    )
+}
+
+/// Whether `err` is the service rejecting a range that starts beyond the end
+/// of the resource.
+pub fn is_invalid_range(err: &Error) -> bool {
+    matches_http_error(
+        err,
+        StatusCode::RequestedRangeNotSatisfiable,
+        &["InvalidRange"],
+    )
+}
+
+/// Whether `err` is the service throttling the account, in which case the
+/// request can be retried after backing off.
+pub fn is_throttled(err: &Error) -> bool {
+    matches_http_error(err, StatusCode::TooManyRequests, THROTTLED_CODES)
+        || matches_http_error(err, StatusCode::ServiceUnavailable, THROTTLED_CODES)
+}
+
+fn matches_http_error(err: &Error, status: StatusCode, codes: &[&str]) -> bool {
+    err.as_http_error()
+        .map(|e| matches(e.status(), e.error_code(), status, codes))
+        .unwrap_or_default()
+}
+
+fn matches(
+    actual_status: StatusCode,
+    actual_code: Option<&str>,
+    status: StatusCode,
+    codes: &[&str],
+) -> bool {
+    actual_status == status || actual_code.is_some_and(|code| codes.contains(&code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_status() {
+        assert!(matches(
+            StatusCode::NotFound,
+            None,
+            StatusCode::NotFound,
+            NOT_FOUND_CODES
+        ));
+        assert!(!matches(
+            StatusCode::Conflict,
+            None,
+            StatusCode::NotFound,
+            NOT_FOUND_CODES
+        ));
+    }
+
+    #[test]
+    fn matches_error_code() {
+        assert!(matches(
+            StatusCode::BadRequest,
+            Some("ConditionNotMet"),
+            StatusCode::PreconditionFailed,
+            PRECONDITION_FAILED_CODES
+        ));
+        assert!(matches(
+            StatusCode::Ok,
+            Some("ServerBusy"),
+            StatusCode::ServiceUnavailable,
+            THROTTLED_CODES
+        ));
+        assert!(!matches(
+            StatusCode::BadRequest,
+            Some("InvalidHeaderValue"),
+            StatusCode::Conflict,
+            CONFLICT_CODES
+        ));
+    }
+
+    #[test]
+    fn non_http_errors_never_match() {
+        let err = Error::message(azure_core::error::ErrorKind::Io, "connection reset");
+        assert!(!is_not_found(&err));
+        assert!(!is_conflict(&err));
+        assert!(!is_precondition_failed(&err));
+        assert!(!is_throttled(&err));
+    }
+}
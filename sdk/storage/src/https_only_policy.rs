@@ -0,0 +1,40 @@
+use azure_core::{
+    error::{Error, ErrorKind},
+    Context, Policy, PolicyResult, Request,
+};
+use std::sync::Arc;
+
+/// Reject any request that is not sent over HTTPS.
+///
+/// This guards against a misconfigured endpoint downgrading requests, and the
+/// credentials they carry, to plaintext HTTP.
+#[derive(Debug, Clone, Default)]
+pub struct HttpsOnlyPolicy;
+
+impl HttpsOnlyPolicy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for HttpsOnlyPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        if request.url().scheme() != "https" {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "refusing to send a request over {}: {}",
+                    request.url().scheme(),
+                    request.url().origin().ascii_serialization()
+                )
+            }));
+        }
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
@@ -30,14 +30,21 @@ mod connection_string;
 mod connection_string_builder;
 mod copy_id;
 mod copy_progress;
+pub mod error;
+mod https_only_policy;
+mod logging_policy;
 mod macros;
 pub mod prelude;
+mod secondary_read_policy;
 pub mod shared_access_signature;
 
 pub use self::connection_string::{ConnectionString, EndpointProtocol};
 pub use self::connection_string_builder::ConnectionStringBuilder;
-pub use authorization::{StorageCredentials, StorageCredentialsInner};
+pub use authorization::{authorize_request, StorageCredentials, StorageCredentialsInner};
 pub use cloud_location::*;
+pub use https_only_policy::HttpsOnlyPolicy;
+pub use logging_policy::LoggingPolicy;
+pub use secondary_read_policy::SecondaryReadPolicy;
 pub mod headers;
 pub use copy_id::{copy_id_from_headers, CopyId};
 pub use copy_progress::CopyProgress;
@@ -0,0 +1,74 @@
+use crate::shared_access_signature::redact_signature;
+use azure_core::{headers::REQUEST_ID, Context, Policy, PolicyResult, Request};
+use std::sync::Arc;
+use time::OffsetDateTime;
+use tracing::Level;
+
+/// Log every request attempt made through the pipeline.
+///
+/// Each attempt is logged once it completes, with its method, URL, status,
+/// duration and request id. Headers are never logged, and the signature of a
+/// SAS token in the URL is redacted. This policy should run after the retry
+/// policy, so that every retry is logged.
+#[derive(Debug, Clone)]
+pub struct LoggingPolicy {
+    level: Level,
+}
+
+impl LoggingPolicy {
+    pub fn new(level: Level) -> Self {
+        Self { level }
+    }
+}
+
+// `tracing` requires the level of an event to be a constant.
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)+) => {
+        match $level {
+            Level::ERROR => tracing::error!($($arg)+),
+            Level::WARN => tracing::warn!($($arg)+),
+            Level::INFO => tracing::info!($($arg)+),
+            Level::DEBUG => tracing::debug!($($arg)+),
+            _ => tracing::trace!($($arg)+),
+        }
+    };
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for LoggingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let method = *request.method();
+        let url = redact_signature(request.url());
+        let start = OffsetDateTime::now_utc();
+
+        let result = next[0].send(ctx, request, &next[1..]).await;
+
+        let duration_ms = (OffsetDateTime::now_utc() - start).whole_milliseconds();
+        match &result {
+            Ok(response) => log_at!(
+                self.level,
+                method = %method,
+                url = %url,
+                status = %response.status(),
+                duration_ms,
+                request_id = response.headers().get_optional_str(&REQUEST_ID),
+                "storage request completed"
+            ),
+            Err(err) => log_at!(
+                self.level,
+                method = %method,
+                url = %url,
+                duration_ms,
+                error = %err,
+                "storage request failed"
+            ),
+        }
+        result
+    }
+}
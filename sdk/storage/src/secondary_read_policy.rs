@@ -0,0 +1,128 @@
+use azure_core::{
+    error::{Error, ErrorKind, ResultExt},
+    Context, Method, Policy, PolicyResult, Request,
+};
+use std::sync::Arc;
+
+/// Retry failed reads against the secondary endpoint of a read-access
+/// geo-redundant (RA-GRS) storage account.
+///
+/// Only `GET` and `HEAD` requests sent to `primary_host` are redirected, and
+/// only after the primary endpoint failed with a server or transport error.
+/// This policy should run before the retry policy, so both endpoints get the
+/// full set of retries.
+#[derive(Debug, Clone)]
+pub struct SecondaryReadPolicy {
+    primary_host: String,
+    secondary_host: String,
+}
+
+impl SecondaryReadPolicy {
+    pub fn new(primary_host: impl Into<String>, secondary_host: impl Into<String>) -> Self {
+        Self {
+            primary_host: primary_host.into(),
+            secondary_host: secondary_host.into(),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for SecondaryReadPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let is_primary_read = matches!(request.method(), Method::Get | Method::Head)
+            && request.url().host_str() == Some(self.primary_host.as_str());
+
+        match next[0].send(ctx, request, &next[1..]).await {
+            Err(err) if is_primary_read && is_unavailable(&err) => {
+                request
+                    .url_mut()
+                    .set_host(Some(&self.secondary_host))
+                    .with_context(ErrorKind::DataConversion, || {
+                        format!("invalid secondary host: {}", self.secondary_host)
+                    })?;
+                next[0].send(ctx, request, &next[1..]).await
+            }
+            result => result,
+        }
+    }
+}
+
+fn is_unavailable(err: &Error) -> bool {
+    match err.kind() {
+        ErrorKind::Io => true,
+        ErrorKind::HttpResponse { status, .. } => status.is_server_error(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{headers::Headers, BytesStream, Response, StatusCode, Url};
+
+    const PRIMARY: &str = "account.blob.core.windows.net";
+    const SECONDARY: &str = "account-secondary.blob.core.windows.net";
+
+    // Fails every request sent to the primary host with `status`, and answers
+    // requests to any other host.
+    #[derive(Debug)]
+    struct FailingPrimary {
+        status: StatusCode,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl Policy for FailingPrimary {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            if request.url().host_str() == Some(PRIMARY) {
+                return Err(Error::message(
+                    ErrorKind::http_response(self.status, None),
+                    "primary failed",
+                ));
+            }
+            Ok(Response::new(
+                StatusCode::Ok,
+                Headers::new(),
+                Box::pin(BytesStream::new_empty()),
+            ))
+        }
+    }
+
+    async fn send(method: Method, status: StatusCode) -> azure_core::Result<Request> {
+        let policy = SecondaryReadPolicy::new(PRIMARY, SECONDARY);
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(FailingPrimary { status })];
+        let url = Url::parse(&format!("https://{PRIMARY}/container/blob"))?;
+        let mut request = Request::new(url, method);
+        policy.send(&Context::new(), &mut request, &next).await?;
+        Ok(request)
+    }
+
+    #[tokio::test]
+    async fn failed_primary_read_goes_to_secondary() -> azure_core::Result<()> {
+        let request = send(Method::Get, StatusCode::ServiceUnavailable).await?;
+        assert_eq!(request.url().host_str(), Some(SECONDARY));
+
+        let request = send(Method::Head, StatusCode::InternalServerError).await?;
+        assert_eq!(request.url().host_str(), Some(SECONDARY));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn writes_and_client_errors_stay_on_primary() {
+        assert!(send(Method::Put, StatusCode::ServiceUnavailable)
+            .await
+            .is_err());
+        assert!(send(Method::Get, StatusCode::NotFound).await.is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use azure_core::Url;
 use std::fmt;
 use time::OffsetDateTime;
 
@@ -8,6 +9,12 @@ pub trait SasToken {
     fn token(&self) -> azure_core::Result<String>;
 }
 
+/// Returns a copy of `url` with the SAS signature (`sig`) replaced by `REDACTED`, so the
+/// URL can be logged without leaking the token.
+pub fn redact_signature(url: &Url) -> Url {
+    azure_core::util::redact_signature(url)
+}
+
 /// Converts an `OffsetDateTime` to an RFC3339 formatted string after truncating
 /// any partial seconds.
 pub(crate) fn format_date(d: OffsetDateTime) -> String {
@@ -50,4 +57,19 @@ mod tests {
         let date = datetime!(2022-08-22 15:11:43.4185122 +00:00:00);
         assert_eq!(format_date(date), "2022-08-22T15:11:43Z");
     }
+
+    #[test]
+    fn test_redact_signature() {
+        let url =
+            Url::parse("https://a.blob.core.windows.net/c/b?sv=2022-11-02&sig=secret%2Fvalue")
+                .unwrap();
+        let redacted = redact_signature(&url);
+        assert_eq!(
+            redacted.as_str(),
+            "https://a.blob.core.windows.net/c/b?sv=2022-11-02&sig=REDACTED"
+        );
+
+        let url = Url::parse("https://a.blob.core.windows.net/c/b").unwrap();
+        assert_eq!(redact_signature(&url), url);
+    }
 }
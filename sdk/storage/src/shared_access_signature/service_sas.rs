@@ -161,6 +161,7 @@ impl BlobSharedAccessSignature {
     }
 
     setters! {
+        expiry: OffsetDateTime => expiry,
         start: OffsetDateTime => Some(start),
         identifier: String => Some(identifier),
         ip: String => Some(ip),
@@ -17,12 +17,18 @@ pub use block_with_size_list::BlockWithSizeList;
 pub use lease_blob_options::{LeaseBlobOptions, LEASE_BLOB_OPTIONS_DEFAULT};
 pub use page_range_list::PageRangeList;
 
-use crate::options::{AccessTier, Snapshot, Tags, SNAPSHOT};
+use crate::options::{
+    AccessTier, Snapshot, Tags, ACCESS_TIER_CHANGE_TIME, ACCESS_TIER_INFERRED, ARCHIVE_STATUS,
+    BLOB_SEALED, ENCRYPTION_SCOPE, IMMUTABILITY_POLICY_MODE, IMMUTABILITY_POLICY_UNTIL_DATE,
+    IS_CURRENT_VERSION, LEGAL_HOLD, REHYDRATE_PRIORITY, SNAPSHOT, VERSION_ID,
+};
 use azure_core::{
     content_type, date,
+    error::{Error, ErrorKind},
     headers::{self, Headers},
     parsing::from_azure_time,
-    Etag, LeaseDuration, LeaseState, LeaseStatus,
+    request_options::{ContentRange, IfMatchCondition},
+    Etag, LeaseDuration, LeaseState, LeaseStatus, StatusCode,
 };
 use azure_storage::{ConsistencyCRC64, ConsistencyMD5, CopyId, CopyProgress};
 use serde::{Deserialize, Deserializer};
@@ -163,6 +169,9 @@ pub struct BlobProperties {
     pub encryption_scope: Option<String>,
     pub incremental_copy: Option<bool>,
     pub access_tier_inferred: Option<bool>,
+    /// Set while an archived blob is being rehydrated, for example
+    /// `rehydrate-pending-to-hot`.
+    pub archive_status: Option<String>,
     #[serde(default, with = "azure_core::date::rfc1123::option")]
     pub deleted_time: Option<OffsetDateTime>,
     pub remaining_retention_days: Option<u32>,
@@ -176,6 +185,18 @@ pub struct BlobProperties {
     pub expiry_time: Option<OffsetDateTime>,
     pub blob_committed_block_count: Option<u64>,
     pub resource_type: Option<String>,
+    #[serde(rename = "Sealed")]
+    pub sealed: Option<bool>,
+    #[serde(
+        default,
+        with = "azure_core::date::rfc1123::option",
+        rename = "ImmutabilityPolicyUntilDate"
+    )]
+    pub immutability_policy_until_date: Option<OffsetDateTime>,
+    #[serde(rename = "ImmutabilityPolicyMode")]
+    pub immutability_policy_mode: Option<String>,
+    #[serde(rename = "LegalHold")]
+    pub legal_hold: Option<bool>,
     #[serde(flatten)]
     extra: HashMap<String, Value>, // For debug purposes, should be compiled out in the future
 }
@@ -198,12 +219,27 @@ impl Blob {
             .unwrap_or(content_type::APPLICATION_OCTET_STREAM.as_str())
             .to_string();
 
-        let content_length = h.get_as(&headers::CONTENT_LENGTH)?;
+        // Chunked responses, and responses the transport has decompressed, carry
+        // no Content-Length. The Content-Range of a ranged read still gives its size.
+        let content_length = match h.get_optional_as(&headers::CONTENT_LENGTH)? {
+            Some(content_length) => content_length,
+            None => h
+                .get_optional_as::<ContentRange, _>(&headers::CONTENT_RANGE)?
+                .map(|content_range| content_range.end() - content_range.start() + 1)
+                .unwrap_or_default(),
+        };
         let last_modified = from_azure_time(h.get_str(&headers::LAST_MODIFIED)?)?;
         let etag = h.get_as(&headers::ETAG)?;
         let blob_sequence_number = h.get_optional_as(&headers::BLOB_SEQUENCE_NUMBER)?;
         let blob_type = h.get_as(&headers::BLOB_TYPE)?;
         let access_tier = h.get_optional_as(&headers::BLOB_ACCESS_TIER)?;
+        let access_tier_inferred = h.get_optional_as(&ACCESS_TIER_INFERRED)?;
+        let access_tier_change_time = h
+            .get_optional_str(&ACCESS_TIER_CHANGE_TIME)
+            .map(date::parse_rfc1123)
+            .transpose()?;
+        let archive_status = h.get_optional_string(&ARCHIVE_STATUS);
+        let rehydrate_priority = h.get_optional_as(&REHYDRATE_PRIORITY)?;
         let content_encoding = h.get_optional_string(&headers::CONTENT_ENCODING);
         let content_language = h.get_optional_string(&headers::CONTENT_LANGUAGE);
         let content_md5 = h.get_optional_as(&headers::CONTENT_MD5)?;
@@ -223,6 +259,14 @@ impl Blob {
         let copy_status_description = h.get_optional_string(&headers::COPY_STATUS_DESCRIPTION);
         let server_encrypted = h.get_as(&headers::SERVER_ENCRYPTED)?;
         let blob_committed_block_count = h.get_optional_as(&headers::BLOB_COMMITTED_BLOCK_COUNT)?;
+        let sealed = h.get_optional_as(&BLOB_SEALED)?;
+        let immutability_policy_until_date = h
+            .get_optional_str(&IMMUTABILITY_POLICY_UNTIL_DATE)
+            .map(date::parse_rfc1123)
+            .transpose()?;
+        let immutability_policy_mode = h.get_optional_string(&IMMUTABILITY_POLICY_MODE);
+        let legal_hold = h.get_optional_as(&LEGAL_HOLD)?;
+        let encryption_scope = h.get_optional_string(&ENCRYPTION_SCOPE);
 
         let mut metadata = HashMap::new();
         for (name, value) in h.iter() {
@@ -240,13 +284,15 @@ impl Blob {
         let tags = h.get_optional_as(&headers::TAGS)?;
 
         let snapshot = h.get_optional_as(&SNAPSHOT)?;
+        let version_id = h.get_optional_string(&VERSION_ID);
+        let is_current_version = h.get_optional_as(&IS_CURRENT_VERSION)?;
 
         Ok(Blob {
             name: blob_name.into(),
             snapshot,
-            deleted: None,            //TODO
-            is_current_version: None, //TODO
-            version_id: None,         //TODO
+            deleted: None, //TODO
+            is_current_version,
+            version_id,
             properties: BlobProperties {
                 creation_time,
                 last_modified,
@@ -275,16 +321,21 @@ impl Blob {
                 incremental_copy: None, // TODO: Not present or documentation bug?
                 server_encrypted,
                 customer_provided_key_sha256: None, // TODO
-                encryption_scope: None,             // TODO
-                access_tier_inferred: None,         // TODO: Not present
-                access_tier_change_time: None,      // TODO: Not present
-                deleted_time: None,                 // TODO
-                remaining_retention_days: None,     // TODO: Not present or documentation bug?
-                tag_count: None,                    // TODO
-                rehydrate_priority: None,           // TODO
+                encryption_scope,
+                access_tier_inferred,
+                access_tier_change_time,
+                archive_status,
+                deleted_time: None,             // TODO
+                remaining_retention_days: None, // TODO: Not present or documentation bug?
+                tag_count: None,                // TODO
+                rehydrate_priority,
                 expiry_time: None,
                 resource_type: None,
                 blob_committed_block_count,
+                sealed,
+                immutability_policy_until_date,
+                immutability_policy_mode,
+                legal_hold,
                 extra: HashMap::new(),
             },
             metadata,
@@ -296,3 +347,144 @@ impl Blob {
 pub(crate) fn copy_status_from_headers(headers: &Headers) -> azure_core::Result<CopyStatus> {
     headers.get_as(&headers::COPY_STATUS)
 }
+
+const MAX_BLOB_NAME_LEN: usize = 1024;
+
+/// Check a blob name against the service's naming rules.
+///
+/// Names must be between 1 and 1024 characters long.
+///
+/// ref: <https://docs.microsoft.com/en-us/rest/api/storageservices/naming-and-referencing-containers--blobs--and-metadata#blob-names>
+pub fn validate_blob_name(name: &str) -> azure_core::Result<()> {
+    if !(1..=MAX_BLOB_NAME_LEN).contains(&name.chars().count()) {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!("blob name must be between 1 and {MAX_BLOB_NAME_LEN} characters: {name:?}")
+        }));
+    }
+
+    Ok(())
+}
+
+/// The match condition an upload sends for its `if_match` and `overwrite`
+/// options.
+///
+/// `overwrite(false)` becomes `If-None-Match: *`, which the service rejects
+/// with `BlobAlreadyExists` when the blob exists. It only applies when no
+/// explicit match condition was set.
+pub(crate) fn overwrite_condition(
+    if_match: Option<IfMatchCondition>,
+    overwrite: Option<bool>,
+) -> Option<IfMatchCondition> {
+    match (if_match, overwrite) {
+        (None, Some(false)) => Some(IfMatchCondition::NotMatch("*".to_owned())),
+        (if_match, _) => if_match,
+    }
+}
+
+/// Whether `err` is the service rejecting a write because the blob already exists,
+/// such as an upload with `overwrite(false)`.
+pub fn is_blob_already_exists(err: &Error) -> bool {
+    err.as_http_error()
+        .map(|e| e.status() == StatusCode::Conflict && e.error_code() == Some("BlobAlreadyExists"))
+        .unwrap_or_default()
+}
+
+/// Whether `err` is the service rejecting an operation meant for a different
+/// blob type, such as appending a block to an existing block blob or writing
+/// pages to an append blob.
+pub fn is_invalid_blob_type(err: &Error) -> bool {
+    err.as_http_error()
+        .map(|e| e.error_code() == Some("InvalidBlobType"))
+        .unwrap_or_default()
+}
+
+/// Whether `err` is the service reporting that a blob has not changed, in
+/// answer to a read made with `IfMatchCondition::NotMatch` or
+/// `IfModifiedSinceCondition::Modified`.
+pub fn is_not_modified(err: &Error) -> bool {
+    err.as_http_error()
+        .map(|e| e.status() == StatusCode::NotModified)
+        .unwrap_or_default()
+}
+
+/// Whether `err` is the service rejecting a request because one of its
+/// conditions, such as `if_match` or `if_tags`, was not met.
+pub fn is_condition_not_met(err: &Error) -> bool {
+    azure_storage::error::is_precondition_failed(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_headers_without_content_length() -> azure_core::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(headers::CREATION_TIME, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(headers::LAST_MODIFIED, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(headers::ETAG, "\"0x8D0\"");
+        headers.insert(headers::BLOB_TYPE, "BlockBlob");
+        headers.insert(headers::SERVER_ENCRYPTED, "true");
+
+        let blob = Blob::from_headers("chunked", &headers)?;
+        assert_eq!(blob.properties.content_length, 0);
+
+        headers.insert(headers::CONTENT_RANGE, "bytes 0-1023/4096");
+        let blob = Blob::from_headers("chunked", &headers)?;
+        assert_eq!(blob.properties.content_length, 1024);
+
+        headers.insert(headers::CONTENT_LENGTH, "10");
+        let blob = Blob::from_headers("chunked", &headers)?;
+        assert_eq!(blob.properties.content_length, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn overwrite_condition_only_without_if_match() {
+        let any = Some(IfMatchCondition::NotMatch("*".to_owned()));
+        let etag = Some(IfMatchCondition::Match("\"0x8D0\"".to_owned()));
+        assert_eq!(overwrite_condition(None, None), None);
+        assert_eq!(overwrite_condition(None, Some(true)), None);
+        assert_eq!(overwrite_condition(None, Some(false)), any);
+        assert_eq!(overwrite_condition(etag.clone(), Some(false)), etag);
+    }
+
+    #[test]
+    fn from_headers_rehydrate_status() -> azure_core::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(headers::CREATION_TIME, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(headers::LAST_MODIFIED, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(headers::ETAG, "\"0x8D0\"");
+        headers.insert(headers::BLOB_TYPE, "BlockBlob");
+        headers.insert(headers::SERVER_ENCRYPTED, "true");
+        headers.insert(headers::BLOB_ACCESS_TIER, "Archive");
+        headers.insert(ACCESS_TIER_INFERRED, "false");
+        headers.insert(ACCESS_TIER_CHANGE_TIME, "Fri, 02 Jan 2015 00:00:00 GMT");
+        headers.insert(ARCHIVE_STATUS, "rehydrate-pending-to-hot");
+        headers.insert(REHYDRATE_PRIORITY, "High");
+
+        let properties = Blob::from_headers("archived", &headers)?.properties;
+        assert_eq!(properties.access_tier, Some(AccessTier::Archive));
+        assert_eq!(properties.access_tier_inferred, Some(false));
+        assert_eq!(
+            properties.access_tier_change_time,
+            Some(date::parse_rfc1123("Fri, 02 Jan 2015 00:00:00 GMT")?)
+        );
+        assert_eq!(
+            properties.archive_status.as_deref(),
+            Some("rehydrate-pending-to-hot")
+        );
+        assert_eq!(properties.rehydrate_priority, Some(RehydratePriority::High));
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_blob_name() {
+        assert!(validate_blob_name("a").is_ok());
+        assert!(validate_blob_name("my/nested/blob.txt").is_ok());
+        assert!(validate_blob_name(&"a".repeat(1024)).is_ok());
+
+        assert!(validate_blob_name("").is_err());
+        assert!(validate_blob_name(&"a".repeat(1025)).is_err());
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{blob::operations::put_block::PutBlockResponse, prelude::*};
-use azure_core::{headers::*, prelude::*, Body};
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, Body, RequestId};
+use azure_storage::{headers::consistency_from_headers, ConsistencyCRC64, ConsistencyMD5};
+use time::OffsetDateTime;
 
 operation! {
     AppendBlock,
@@ -39,9 +41,46 @@ impl AppendBlockBuilder {
 
             let response = self.client.send(&mut self.context, &mut request).await?;
 
-            PutBlockResponse::from_headers(response.headers())
+            AppendBlockResponse::from_headers(response.headers())
         })
     }
 }
 
-type AppendBlockResponse = PutBlockResponse;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppendBlockResponse {
+    pub etag: String,
+    pub last_modified: OffsetDateTime,
+    pub content_md5: Option<ConsistencyMD5>,
+    pub content_crc64: Option<ConsistencyCRC64>,
+    pub request_id: RequestId,
+    pub date: OffsetDateTime,
+    pub request_server_encrypted: bool,
+    /// The offset at which the block was committed.
+    pub blob_append_offset: Option<u64>,
+    pub blob_committed_block_count: Option<u64>,
+}
+
+impl AppendBlockResponse {
+    pub(crate) fn from_headers(headers: &Headers) -> azure_core::Result<AppendBlockResponse> {
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let (content_md5, content_crc64) = consistency_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let request_server_encrypted = request_server_encrypted_from_headers(headers)?;
+        let blob_append_offset = headers.get_optional_as(&BLOB_APPEND_OFFSET)?;
+        let blob_committed_block_count = headers.get_optional_as(&BLOB_COMMITTED_BLOCK_COUNT)?;
+
+        Ok(AppendBlockResponse {
+            etag,
+            last_modified,
+            content_md5,
+            content_crc64,
+            request_id,
+            date,
+            request_server_encrypted,
+            blob_append_offset,
+            blob_committed_block_count,
+        })
+    }
+}
@@ -6,7 +6,8 @@ operation! {
     BreakLease,
     client: BlobClient,
     ?lease_break_period: LeaseBreakPeriod,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?if_tags: IfTags
 }
 
 impl BreakLeaseBuilder {
@@ -20,6 +21,7 @@ impl BreakLeaseBuilder {
             headers.insert(LEASE_ACTION, "break");
             headers.add(self.lease_break_period);
             headers.add(self.lease_id);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
@@ -9,7 +9,8 @@ operation! {
     ?if_sequence_number: IfSequenceNumber,
     ?if_modified_since: IfModifiedSinceCondition,
     ?if_match: IfMatchCondition,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?if_tags: IfTags
 }
 
 impl ClearPageBuilder {
@@ -27,6 +28,7 @@ impl ClearPageBuilder {
             headers.add(self.if_modified_since);
             headers.add(self.if_match);
             headers.add(self.lease_id);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
@@ -16,6 +16,7 @@ operation! {
     ?if_match: IfMatchCondition,
     ?if_tags: IfTags,
     ?access_tier: AccessTier,
+    ?tags: Tags,
     ?lease_id: LeaseId,
     ?if_source_since: IfSourceModifiedSinceCondition,
     ?if_source_match: IfSourceMatchCondition,
@@ -39,6 +40,7 @@ impl CopyBlobBuilder {
             headers.add(self.if_modified_since);
             headers.add(self.if_match);
             headers.add(self.access_tier);
+            Tags::add_header(&mut headers, self.tags)?;
             headers.add(self.lease_id);
             headers.add(self.if_source_since);
             headers.add(self.if_source_match);
@@ -68,6 +70,7 @@ pub struct CopyBlobResponse {
     pub date: OffsetDateTime,
     pub copy_id: CopyId,
     pub copy_status: CopyStatus,
+    pub version_id: Option<VersionId>,
 }
 
 impl TryFrom<&Headers> for CopyBlobResponse {
@@ -83,6 +86,7 @@ impl TryFrom<&Headers> for CopyBlobResponse {
             date: date_from_headers(headers)?,
             copy_id: copy_id_from_headers(headers)?,
             copy_status: copy_status_from_headers(headers)?,
+            version_id: headers.get_optional_as(&VERSION_ID)?,
         })
     }
 }
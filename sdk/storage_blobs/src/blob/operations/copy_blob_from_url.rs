@@ -14,12 +14,15 @@ operation! {
     source_url: Url,
     ?is_synchronous: bool,
     ?metadata: Metadata,
+    ?access_tier: AccessTier,
+    ?tags: Tags,
     ?if_modified_since: IfModifiedSinceCondition,
     ?if_match: IfMatchCondition,
     ?if_source_since: IfSourceModifiedSinceCondition,
     ?if_source_match: IfSourceMatchCondition,
     ?lease_id: LeaseId,
-    ?source_content_md5: SourceContentMD5
+    ?source_content_md5: SourceContentMD5,
+    ?if_tags: IfTags
 }
 
 impl CopyBlobFromUrlBuilder {
@@ -38,12 +41,15 @@ impl CopyBlobFromUrlBuilder {
                     headers.add(m);
                 }
             }
+            headers.add(self.access_tier);
+            Tags::add_header(&mut headers, self.tags)?;
             headers.add(self.if_modified_since);
             headers.add(self.if_match);
             headers.add(self.lease_id);
             headers.add(self.if_source_since);
             headers.add(self.if_source_match);
             headers.add(self.source_content_md5);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
@@ -66,6 +72,7 @@ pub struct CopyBlobFromUrlResponse {
     pub copy_id: CopyId,
     pub copy_status: CopyStatus,
     pub date: OffsetDateTime,
+    pub version_id: Option<VersionId>,
 }
 
 impl TryFrom<&Headers> for CopyBlobFromUrlResponse {
@@ -81,6 +88,7 @@ impl TryFrom<&Headers> for CopyBlobFromUrlResponse {
             copy_id: copy_id_from_headers(headers)?,
             copy_status: copy_status_from_headers(headers)?,
             date: date_from_headers(headers)?,
+            version_id: headers.get_optional_as(&VERSION_ID)?,
         })
     }
 }
@@ -6,7 +6,8 @@ operation! {
     client: BlobClient,
     snapshot: Snapshot,
     ?permanent: bool,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?if_tags: IfTags
 }
 
 impl DeleteBlobSnapshotBuilder {
@@ -22,6 +23,7 @@ impl DeleteBlobSnapshotBuilder {
 
             let mut headers = Headers::new();
             headers.add(self.lease_id);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Delete, headers, None)?;
@@ -6,7 +6,8 @@ operation! {
     client: BlobClient,
     version_id: VersionId,
     ?permanent: bool,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?if_tags: IfTags
 }
 
 impl DeleteBlobVersionBuilder {
@@ -21,6 +22,7 @@ impl DeleteBlobVersionBuilder {
 
             let mut headers = Headers::new();
             headers.add(self.lease_id);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Delete, headers, None)?;
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    DeleteImmutabilityPolicy,
+    client: BlobClient,
+    ?blob_versioning: BlobVersioning
+}
+
+impl DeleteImmutabilityPolicyBuilder {
+    pub fn into_future(mut self) -> DeleteImmutabilityPolicy {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+            url.query_pairs_mut()
+                .append_pair("comp", "immutabilityPolicies");
+            self.blob_versioning.append_to_url_query(&mut url);
+
+            let mut request = BlobClient::finalize_request(
+                url,
+                azure_core::Method::Delete,
+                Headers::new(),
+                None,
+            )?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+            DeleteImmutabilityPolicyResponse::from_headers(response.headers())
+        })
+    }
+}
+
+azure_storage::response_from_headers!(DeleteImmutabilityPolicyResponse ,
+    request_id_from_headers => request_id: RequestId,
+    date_from_headers => date: OffsetDateTime
+);
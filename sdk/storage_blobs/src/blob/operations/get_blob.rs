@@ -1,12 +1,28 @@
 use crate::prelude::*;
 use azure_core::{
-    error::Error, headers::*, prelude::*, Pageable, RequestId, Response as AzureResponse,
-    ResponseBody,
+    error::{Error, ErrorKind},
+    headers::*,
+    prelude::*,
+    Pageable, RequestId, Response as AzureResponse, ResponseBody, Url,
+};
+use azure_storage::error::is_invalid_range;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+#[cfg(feature = "tokio-fs")]
+use std::path::Path;
+use std::{
+    pin::Pin,
+    sync::{Mutex, PoisonError},
+    task::{Context as TaskContext, Poll},
 };
 use time::OffsetDateTime;
 
 const DEFAULT_CHUNK_SIZE: u64 = 0x1000 * 0x1000;
 
+/// How many times a single chunk is resumed before giving up, when
+/// `resume_on_failure` is set.
+const MAX_RESUME_ATTEMPTS: u32 = 5;
+
 operation! {
     #[stream]
     GetBlob,
@@ -19,6 +35,7 @@ operation! {
     ?if_modified_since: IfModifiedSinceCondition,
     ?if_match: IfMatchCondition,
     ?if_tags: IfTags,
+    ?resume_on_failure: bool,
 }
 
 impl GetBlobBuilder {
@@ -29,6 +46,7 @@ impl GetBlobBuilder {
             async move {
                 let mut url = this.client.url()?;
 
+                let is_first_unranged = continuation.is_none() && this.range.is_none();
                 let range = match continuation {
                     Some(range) => range,
                     None => initial_range(
@@ -40,26 +58,194 @@ impl GetBlobBuilder {
                 this.blob_versioning.append_to_url_query(&mut url);
 
                 let mut headers = Headers::new();
-                for (name, value) in range.as_headers() {
-                    headers.insert(name, value);
-                }
-
                 headers.add(this.lease_id);
                 headers.add(this.encryption_key.as_ref());
                 headers.add(this.if_modified_since);
                 headers.add(this.if_match.clone());
                 headers.add(this.if_tags.clone());
 
-                let mut request =
-                    BlobClient::finalize_request(url, azure_core::Method::Get, headers, None)?;
+                let mut ranged_headers = headers.clone();
+                for (name, value) in range.as_headers() {
+                    ranged_headers.insert(name, value);
+                }
 
-                let response = this.client.send(&mut ctx, &mut request).await?;
+                // If the caller did not ask for a range, an empty blob is fetched whole.
+                let response = send_ranged_get(
+                    &this.client,
+                    &mut ctx,
+                    &url,
+                    ranged_headers,
+                    is_first_unranged.then(|| headers.clone()),
+                )
+                .await?;
+
+                let response = if this.resume_on_failure.unwrap_or_default() {
+                    resuming_response(&this, ctx, url, headers, response)?
+                } else {
+                    response
+                };
 
                 GetBlobResponse::try_from(this, response)
             }
         };
         Pageable::new(make_request)
     }
+
+    /// Download the blob to a file on disk, returning the number of bytes written.
+    ///
+    /// This behaves like `BlobClient::download_to_file`, but honours the options
+    /// set on this builder, such as `resume_on_failure` and `chunk_size`. The whole
+    /// blob is always downloaded, so setting a `range` is an error.
+    #[cfg(feature = "tokio-fs")]
+    pub async fn download_to_file(self, path: impl AsRef<Path>) -> azure_core::Result<u64> {
+        if self.range.is_some() {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "download_to_file does not support a range",
+            ));
+        }
+        let client = self.client.clone();
+        client.download_blob_to_file(self, path.as_ref()).await
+    }
+}
+
+/// Send a ranged GET for a blob.
+///
+/// The service rejects any range on an empty blob. When `unranged_headers` is
+/// given, that rejection is answered by fetching the whole (empty) blob with
+/// those headers instead.
+pub(crate) async fn send_ranged_get(
+    client: &BlobClient,
+    ctx: &mut Context,
+    url: &Url,
+    ranged_headers: Headers,
+    unranged_headers: Option<Headers>,
+) -> azure_core::Result<AzureResponse> {
+    let mut request =
+        BlobClient::finalize_request(url.clone(), azure_core::Method::Get, ranged_headers, None)?;
+    match (client.send(ctx, &mut request).await, unranged_headers) {
+        (Err(err), Some(headers)) if is_invalid_range(&err) => {
+            let mut request =
+                BlobClient::finalize_request(url.clone(), azure_core::Method::Get, headers, None)?;
+            client.send(ctx, &mut request).await
+        }
+        (response, _) => response,
+    }
+}
+
+// Stream the body of a chunk. If the connection drops part way through, the
+// bytes still missing are requested again, with `If-Match` set to the blob's
+// etag so that a blob changed in the meantime fails the download rather than
+// mixing content.
+fn resuming_response(
+    request: &GetBlobBuilder,
+    ctx: Context,
+    url: Url,
+    headers: Headers,
+    response: AzureResponse,
+) -> azure_core::Result<AzureResponse> {
+    let (status, response_headers, body) = response.deconstruct();
+    let content_range: Option<ContentRange> = response_headers.get_optional_as(&CONTENT_RANGE)?;
+    let body = ResumingBody {
+        client: request.client.clone(),
+        ctx,
+        url,
+        headers,
+        etag: etag_from_headers(&response_headers)?,
+        offset: content_range.map(|cr| cr.start()).unwrap_or_default(),
+        end: content_range.map(|cr| cr.end() + 1),
+        attempts: 0,
+        body: Some(body),
+    };
+    let body = futures::stream::unfold(body, |mut body| async move {
+        let chunk = body.next_chunk().await?;
+        Some((chunk, body))
+    });
+    Ok(AzureResponse::new(
+        status,
+        response_headers,
+        Box::pin(SyncStream(Mutex::new(Box::pin(body)))),
+    ))
+}
+
+struct ResumingBody {
+    client: BlobClient,
+    ctx: Context,
+    url: Url,
+    headers: Headers,
+    etag: String,
+    offset: u64,
+    end: Option<u64>,
+    attempts: u32,
+    body: Option<ResponseBody>,
+}
+
+impl ResumingBody {
+    async fn next_chunk(&mut self) -> Option<azure_core::Result<Bytes>> {
+        loop {
+            match self.body.as_mut()?.next().await? {
+                Ok(chunk) => {
+                    self.offset += chunk.len() as u64;
+                    return Some(Ok(chunk));
+                }
+                Err(err) => {
+                    if let Err(err) = self.resume(err).await {
+                        self.body = None;
+                        return Some(Err(err));
+                    }
+                }
+            }
+        }
+    }
+
+    async fn resume(&mut self, err: Error) -> azure_core::Result<()> {
+        self.attempts += 1;
+        if self.attempts > MAX_RESUME_ATTEMPTS {
+            return Err(err);
+        }
+        let range: Range = match self.end {
+            Some(end) if self.offset >= end => {
+                self.body = None;
+                return Ok(());
+            }
+            Some(end) => (self.offset..end).into(),
+            None => (self.offset..).into(),
+        };
+        tracing::debug!(
+            "resuming download of blob {} at byte {}: {err}",
+            self.client.blob_name(),
+            self.offset
+        );
+
+        let mut headers = self.headers.clone();
+        for (name, value) in range.as_headers() {
+            headers.insert(name, value);
+        }
+        headers.insert(IF_MATCH, self.etag.clone());
+        let mut retry =
+            BlobClient::finalize_request(self.url.clone(), azure_core::Method::Get, headers, None)?;
+        let response = self.client.send(&mut self.ctx, &mut retry).await?;
+        self.body = Some(response.into_body());
+        Ok(())
+    }
+}
+
+// Response bodies have to be `Sync`, which the futures held by a resuming body
+// are not. The stream is only ever polled through `&mut self`, so the mutex is
+// never contended; it only makes the wrapper `Sync`.
+struct SyncStream<S>(Mutex<Pin<Box<S>>>);
+
+impl<S: Stream> Stream for SyncStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<S::Item>> {
+        self.get_mut()
+            .0
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+            .poll_next(cx)
+    }
 }
 
 #[derive(Debug)]
@@ -69,6 +255,13 @@ pub struct GetBlobResponse {
     pub data: ResponseBody,
     pub date: OffsetDateTime,
     pub content_range: Option<Range>,
+    /// Total size of the blob, taken from the `Content-Range` header when only part of the
+    /// blob was returned.
+    ///
+    /// `None` when the response carries neither `Content-Length` nor `Content-Range`, as
+    /// chunked and decompressed responses do; the size is then only known once `data` has
+    /// been read.
+    pub blob_size: Option<u64>,
     pub remaining_range: Option<Range>,
 }
 
@@ -87,6 +280,12 @@ impl GetBlobResponse {
             content_range,
         );
         let blob = Blob::from_headers(request.client.blob_name(), headers)?;
+        let blob_size = match content_range {
+            Some(content_range) => Some(content_range.total_length()),
+            None => headers
+                .get_optional_str(&CONTENT_LENGTH)
+                .map(|_| blob.properties.content_length),
+        };
         let data = response.into_body();
 
         Ok(Self {
@@ -95,6 +294,7 @@ impl GetBlobResponse {
             data,
             date,
             content_range: content_range.map(|cr| Range::new(cr.start(), cr.end())),
+            blob_size,
             remaining_range,
         })
     }
@@ -172,6 +372,43 @@ fn remaining_range(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use azure_core::StatusCode;
+    use azure_storage::StorageCredentials;
+
+    fn blob_headers() -> Headers {
+        let mut headers = Headers::new();
+        headers.insert(REQUEST_ID, "00000000-0000-0000-0000-000000000000");
+        headers.insert(DATE, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(CREATION_TIME, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(LAST_MODIFIED, "Thu, 01 Jan 2015 00:00:00 GMT");
+        headers.insert(ETAG, "\"0x8D0\"");
+        headers.insert(BLOB_TYPE, "BlockBlob");
+        headers.insert(SERVER_ENCRYPTED, "true");
+        headers
+    }
+
+    fn get_blob_builder() -> GetBlobBuilder {
+        ClientBuilder::new("test", StorageCredentials::anonymous())
+            .blob_client("container", "blob")
+            .get()
+    }
+
+    #[tokio::test]
+    async fn chunked_response_size_unknown() -> azure_core::Result<()> {
+        let data: azure_core::Result<Bytes> = Ok(Bytes::from_static(b"chunked"));
+        let response = AzureResponse::new(
+            StatusCode::Ok,
+            blob_headers(),
+            Box::pin(futures::stream::iter(vec![data])),
+        );
+
+        let response = GetBlobResponse::try_from(get_blob_builder(), response)?;
+        assert_eq!(response.blob.properties.content_length, 0);
+        assert_eq!(response.blob_size, None);
+        assert_eq!(response.remaining_range, None);
+        assert_eq!(response.data.collect().await?, b"chunked"[..]);
+        Ok(())
+    }
 
     #[test]
     fn test_initial_range() -> azure_core::Result<()> {
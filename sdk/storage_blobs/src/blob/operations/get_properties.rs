@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{blob::operations::get_blob::send_ranged_get, prelude::*};
 use azure_core::{headers::*, prelude::*, RequestId};
 use time::OffsetDateTime;
 
@@ -9,7 +9,8 @@ operation! {
     ?if_match: IfMatchCondition,
     ?if_tags: IfTags,
     ?blob_versioning: BlobVersioning,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?prefer_get: bool
 }
 
 impl GetPropertiesBuilder {
@@ -25,13 +26,39 @@ impl GetPropertiesBuilder {
             headers.add(self.if_match);
             headers.add(self.if_tags);
 
-            let mut request =
-                BlobClient::finalize_request(url, azure_core::Method::Head, headers, None)?;
+            if !self.prefer_get.unwrap_or_default() {
+                let mut request =
+                    BlobClient::finalize_request(url, azure_core::Method::Head, headers, None)?;
 
-            let response = self.client.send(&mut self.context, &mut request).await?;
-            // TODO: Fix this
-            //let blob = Blob::from_headers(&blob_name, &container_name, snapshot_time, &headers)?;
-            let blob = Blob::from_headers(self.client.blob_name(), response.headers())?;
+                let response = self.client.send(&mut self.context, &mut request).await?;
+                // TODO: Fix this
+                //let blob = Blob::from_headers(&blob_name, &container_name, snapshot_time, &headers)?;
+                let blob = Blob::from_headers(self.client.blob_name(), response.headers())?;
+                return GetPropertiesResponse::from_response(response.headers(), blob);
+            }
+
+            // Some proxies block HEAD requests. A single byte GET returns the same
+            // headers, with the blob size reported in Content-Range and the MD5 of
+            // the whole blob in x-ms-blob-content-md5.
+            let mut ranged_headers = headers.clone();
+            ranged_headers.insert(MS_RANGE, "bytes=0-0");
+            let response = send_ranged_get(
+                &self.client,
+                &mut self.context,
+                &url,
+                ranged_headers,
+                Some(headers),
+            )
+            .await?;
+
+            let mut blob = Blob::from_headers(self.client.blob_name(), response.headers())?;
+            let content_range: Option<ContentRange> =
+                response.headers().get_optional_as(&CONTENT_RANGE)?;
+            if let Some(content_range) = content_range {
+                blob.properties.content_length = content_range.total_length();
+                blob.properties.content_md5 =
+                    response.headers().get_optional_as(&BLOB_CONTENT_MD5)?;
+            }
             GetPropertiesResponse::from_response(response.headers(), blob)
         })
     }
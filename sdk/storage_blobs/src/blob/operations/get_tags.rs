@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use azure_core::{headers::*, prelude::*, RequestId, ResponseBody};
+use azure_core::{headers::*, prelude::*, xml::read_xml, RequestId, ResponseBody};
+use bytes::Bytes;
 use time::OffsetDateTime;
 
 operation! {
@@ -38,7 +39,10 @@ impl GetTagsBuilder {
 pub struct GetTagsResponse {
     pub request_id: RequestId,
     pub date: OffsetDateTime,
+    /// The tags in the order the service returned them, duplicates included.
     pub tags: Tags,
+    /// The XML body of the response, as received.
+    pub raw: Bytes,
 }
 
 impl GetTagsResponse {
@@ -48,12 +52,14 @@ impl GetTagsResponse {
     ) -> azure_core::Result<Self> {
         let request_id = request_id_from_headers(&headers)?;
         let date = date_from_headers(&headers)?;
-        let tags = body.xml().await?;
+        let raw = body.collect().await?;
+        let tags = read_xml(&raw)?;
 
         Ok(Self {
             request_id,
             date,
             tags,
+            raw,
         })
     }
 }
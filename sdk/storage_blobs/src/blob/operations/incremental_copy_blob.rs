@@ -0,0 +1,63 @@
+use crate::{
+    blob::{copy_status_from_headers, CopyStatus},
+    prelude::*,
+};
+use azure_core::{headers::*, prelude::*, RequestId, Url};
+use azure_storage::{copy_id_from_headers, CopyId};
+use time::OffsetDateTime;
+
+operation! {
+    IncrementalCopyBlob,
+    client: BlobClient,
+    source_url: Url,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?if_tags: IfTags
+}
+
+impl IncrementalCopyBlobBuilder {
+    pub fn into_future(mut self) -> IncrementalCopyBlob {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+            url.query_pairs_mut().append_pair("comp", "incrementalcopy");
+
+            let mut headers = Headers::new();
+            headers.insert(COPY_SOURCE, self.source_url.as_str().to_owned());
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.if_tags);
+
+            let mut request =
+                BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            (response.headers()).try_into()
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncrementalCopyBlobResponse {
+    pub etag: String,
+    pub last_modified: OffsetDateTime,
+    pub request_id: RequestId,
+    pub date: OffsetDateTime,
+    pub copy_id: CopyId,
+    pub copy_status: CopyStatus,
+}
+
+impl TryFrom<&Headers> for IncrementalCopyBlobResponse {
+    type Error = azure_core::Error;
+
+    fn try_from(headers: &Headers) -> azure_core::Result<Self> {
+        Ok(Self {
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            copy_id: copy_id_from_headers(headers)?,
+            copy_status: copy_status_from_headers(headers)?,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+mod abort_copy_blob;
 mod acquire_lease;
 mod append_block;
 mod break_lease;
@@ -8,12 +9,14 @@ mod copy_blob_from_url;
 mod delete_blob;
 mod delete_blob_snapshot;
 mod delete_blob_version;
+mod delete_immutability_policy;
 mod get_blob;
 mod get_block_list;
 mod get_metadata;
 mod get_page_ranges;
 mod get_properties;
 mod get_tags;
+mod incremental_copy_blob;
 mod put_append_blob;
 mod put_block;
 mod put_block_blob;
@@ -21,15 +24,24 @@ mod put_block_list;
 mod put_block_url;
 mod put_page;
 mod put_page_blob;
+mod query_blob;
 mod release_lease;
 mod renew_lease;
+mod resize_page_blob;
+mod seal_append_blob;
 mod set_blob_tier;
 mod set_expiry;
+mod set_immutability_policy;
+mod set_legal_hold;
 mod set_metadata;
 mod set_properties;
 mod set_tags;
 mod snapshot_blob;
+mod undelete_blob;
+#[cfg(feature = "tokio-fs")]
+mod upload_file;
 
+pub use abort_copy_blob::*;
 pub use acquire_lease::*;
 pub use append_block::*;
 pub use break_lease::*;
@@ -40,12 +52,14 @@ pub use copy_blob_from_url::*;
 pub use delete_blob::*;
 pub use delete_blob_snapshot::*;
 pub use delete_blob_version::*;
+pub use delete_immutability_policy::*;
 pub use get_blob::*;
 pub use get_block_list::*;
 pub use get_metadata::*;
 pub use get_page_ranges::*;
 pub use get_properties::*;
 pub use get_tags::*;
+pub use incremental_copy_blob::*;
 pub use put_append_blob::*;
 pub use put_block::*;
 pub use put_block_blob::*;
@@ -53,11 +67,19 @@ pub use put_block_list::*;
 pub use put_block_url::*;
 pub use put_page::*;
 pub use put_page_blob::*;
+pub use query_blob::*;
 pub use release_lease::*;
 pub use renew_lease::*;
+pub use resize_page_blob::*;
+pub use seal_append_blob::*;
 pub use set_blob_tier::*;
 pub use set_expiry::*;
+pub use set_immutability_policy::*;
+pub use set_legal_hold::*;
 pub use set_metadata::*;
 pub use set_properties::*;
 pub use set_tags::*;
 pub use snapshot_blob::*;
+pub use undelete_blob::*;
+#[cfg(feature = "tokio-fs")]
+pub use upload_file::*;
@@ -11,7 +11,9 @@ operation! {
     ?content_disposition: BlobContentDisposition,
     ?metadata: Metadata,
     ?tags: Tags,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?encryption_scope: EncryptionScope,
+    ?if_tags: IfTags
 }
 
 impl PutAppendBlobBuilder {
@@ -25,13 +27,15 @@ impl PutAppendBlobBuilder {
             headers.add(self.content_encoding);
             headers.add(self.content_language);
             headers.add(self.content_disposition);
-            headers.add(self.tags);
+            Tags::add_header(&mut headers, self.tags)?;
             if let Some(metadata) = &self.metadata {
                 for m in metadata.iter() {
                     headers.add(m);
                 }
             }
             headers.add(self.lease_id);
+            headers.add(self.encryption_scope);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
@@ -51,6 +55,7 @@ pub struct PutBlobResponse {
     pub request_id: RequestId,
     pub date: OffsetDateTime,
     pub request_server_encrypted: bool,
+    pub version_id: Option<VersionId>,
 }
 
 impl PutBlobResponse {
@@ -60,6 +65,7 @@ impl PutBlobResponse {
         let request_id = request_id_from_headers(headers)?;
         let date = date_from_headers(headers)?;
         let request_server_encrypted = request_server_encrypted_from_headers(headers)?;
+        let version_id = headers.get_optional_as(&VERSION_ID)?;
 
         Ok(PutBlobResponse {
             etag,
@@ -67,6 +73,7 @@ impl PutBlobResponse {
             request_id,
             date,
             request_server_encrypted,
+            version_id,
         })
     }
 }
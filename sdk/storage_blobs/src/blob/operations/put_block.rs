@@ -9,7 +9,8 @@ operation! {
     block_id: BlockId,
     body: Body,
     ?hash: Hash,
-    ?lease_id: LeaseId
+    ?lease_id: LeaseId,
+    ?encryption_scope: EncryptionScope
 }
 
 impl PutBlockBuilder {
@@ -22,6 +23,7 @@ impl PutBlockBuilder {
 
             let mut headers = Headers::new();
             headers.add(self.lease_id);
+            headers.add(self.encryption_scope);
 
             let mut request = BlobClient::finalize_request(
                 url,
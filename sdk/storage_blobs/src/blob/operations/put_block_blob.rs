@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{blob::overwrite_condition, prelude::*};
 use azure_core::{headers::*, prelude::*, Body, RequestId};
 use azure_storage::{headers::consistency_from_headers, ConsistencyCRC64, ConsistencyMD5};
 use time::OffsetDateTime;
@@ -20,7 +20,8 @@ operation! {
     ?encryption_scope: EncryptionScope,
     ?if_modified_since: IfModifiedSinceCondition,
     ?if_match: IfMatchCondition,
-    ?if_tags: IfTags
+    ?if_tags: IfTags,
+    ?overwrite: bool
 }
 
 impl PutBlockBlobBuilder {
@@ -35,7 +36,7 @@ impl PutBlockBlobBuilder {
             headers.add(self.content_encoding);
             headers.add(self.content_language);
             headers.add(self.content_disposition);
-            headers.add(self.tags);
+            Tags::add_header(&mut headers, self.tags)?;
             if let Some(metadata) = &self.metadata {
                 for m in metadata.iter() {
                     headers.add(m);
@@ -46,7 +47,7 @@ impl PutBlockBlobBuilder {
             headers.add(self.encryption_key);
             headers.add(self.encryption_scope);
             headers.add(self.if_modified_since);
-            headers.add(self.if_match);
+            headers.add(overwrite_condition(self.if_match, self.overwrite));
             headers.add(self.if_tags);
 
             let mut request = BlobClient::finalize_request(
@@ -71,6 +72,7 @@ pub struct PutBlockBlobResponse {
     pub request_id: RequestId,
     pub date: OffsetDateTime,
     pub request_server_encrypted: bool,
+    pub version_id: Option<VersionId>,
 }
 
 impl PutBlockBlobResponse {
@@ -81,6 +83,7 @@ impl PutBlockBlobResponse {
         let request_id = request_id_from_headers(headers)?;
         let date = date_from_headers(headers)?;
         let request_server_encrypted = request_server_encrypted_from_headers(headers)?;
+        let version_id = headers.get_optional_as(&VERSION_ID)?;
 
         Ok(PutBlockBlobResponse {
             etag,
@@ -90,6 +93,7 @@ impl PutBlockBlobResponse {
             request_id,
             date,
             request_server_encrypted,
+            version_id,
         })
     }
 }
@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{blob::overwrite_condition, prelude::*};
 #[cfg(feature = "md5")]
 use azure_core::base64;
 use azure_core::{headers::*, prelude::*, RequestId};
@@ -19,9 +19,11 @@ operation! {
     ?access_tier: AccessTier,
     ?tags: Tags,
     ?lease_id: LeaseId,
+    ?encryption_scope: EncryptionScope,
     ?if_modified_since: IfModifiedSinceCondition,
     ?if_match: IfMatchCondition,
-    ?if_tags: IfTags
+    ?if_tags: IfTags,
+    ?overwrite: bool
 }
 
 impl PutBlockListBuilder {
@@ -50,7 +52,7 @@ impl PutBlockListBuilder {
             headers.add(self.content_language);
             headers.add(self.content_disposition);
             headers.add(self.content_md5);
-            headers.add(self.tags);
+            Tags::add_header(&mut headers, self.tags)?;
             if let Some(metadata) = &self.metadata {
                 for m in metadata.iter() {
                     headers.add(m);
@@ -58,8 +60,9 @@ impl PutBlockListBuilder {
             }
             headers.add(self.access_tier);
             headers.add(self.lease_id);
+            headers.add(self.encryption_scope);
             headers.add(self.if_modified_since);
-            headers.add(self.if_match);
+            headers.add(overwrite_condition(self.if_match, self.overwrite));
             headers.add(self.if_tags);
 
             let mut request = BlobClient::finalize_request(
@@ -83,6 +86,7 @@ pub struct PutBlockListResponse {
     pub request_id: RequestId,
     pub date: OffsetDateTime,
     pub request_server_encrypted: bool,
+    pub version_id: Option<VersionId>,
 }
 
 impl PutBlockListResponse {
@@ -93,6 +97,7 @@ impl PutBlockListResponse {
         let request_id = request_id_from_headers(headers)?;
         let date = date_from_headers(headers)?;
         let request_server_encrypted = request_server_encrypted_from_headers(headers)?;
+        let version_id = headers.get_optional_as(&VERSION_ID)?;
 
         Ok(PutBlockListResponse {
             etag,
@@ -101,6 +106,7 @@ impl PutBlockListResponse {
             request_id,
             date,
             request_server_encrypted,
+            version_id,
         })
     }
 }
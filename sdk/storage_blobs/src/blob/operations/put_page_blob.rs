@@ -15,7 +15,9 @@ operation! {
     ?metadata: Metadata,
     ?tags: Tags,
     ?lease_id: LeaseId,
-    ?sequence_number: SequenceNumber
+    ?encryption_scope: EncryptionScope,
+    ?sequence_number: SequenceNumber,
+    ?if_tags: IfTags
 }
 
 impl PutPageBlobBuilder {
@@ -30,14 +32,16 @@ impl PutPageBlobBuilder {
             headers.add(self.content_encoding);
             headers.add(self.content_language);
             headers.add(self.content_disposition);
-            headers.add(self.tags);
+            Tags::add_header(&mut headers, self.tags)?;
             if let Some(metadata) = &self.metadata {
                 for m in metadata.iter() {
                     headers.add(m);
                 }
             }
             headers.add(self.lease_id);
+            headers.add(self.encryption_scope);
             headers.add(self.sequence_number);
+            headers.add(self.if_tags);
 
             let mut request =
                 BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
@@ -0,0 +1,453 @@
+use crate::prelude::*;
+use azure_core::{
+    error::{Error, ErrorKind},
+    headers::*,
+    prelude::*,
+    xml::to_xml,
+    Method,
+};
+use azure_storage::headers::CommonStorageResponseHeaders;
+use bytes::{Bytes, BytesMut};
+
+operation! {
+    QueryBlob,
+    client: BlobClient,
+    expression: String,
+    ?input_format: QueryFormat,
+    ?output_format: QueryFormat,
+    ?lease_id: LeaseId,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?if_tags: IfTags,
+}
+
+impl QueryBlobBuilder {
+    pub fn into_future(mut self) -> QueryBlob {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+            url.query_pairs_mut().append_pair("comp", "query");
+
+            let body = QueryRequest {
+                query_type: "SQL",
+                expression: &self.expression,
+                input_serialization: self.input_format.as_ref().map(Serialization::from),
+                output_serialization: self.output_format.as_ref().map(Serialization::from),
+            }
+            .encode()?;
+
+            let mut headers = Headers::new();
+            headers.insert(CONTENT_TYPE, "application/xml");
+            headers.add(self.lease_id);
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.if_tags);
+
+            let mut request =
+                BlobClient::finalize_request(url, Method::Post, headers, Some(body.into()))?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            let (_, headers, body) = response.deconstruct();
+            let body = body.collect().await?;
+            QueryBlobResponse::try_from(&headers, &body)
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "QueryRequest", rename_all = "PascalCase")]
+struct QueryRequest<'a> {
+    query_type: &'static str,
+    expression: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_serialization: Option<Serialization<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_serialization: Option<Serialization<'a>>,
+}
+
+impl QueryRequest<'_> {
+    fn encode(&self) -> azure_core::Result<Bytes> {
+        let mut body = BytesMut::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>");
+        body.extend(to_xml(self)?);
+        Ok(body.freeze())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Serialization<'a> {
+    format: Format<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Format<'a> {
+    #[serde(rename = "Type")]
+    format_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delimited_text_configuration: Option<&'a DelimitedTextConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    json_text_configuration: Option<&'a JsonTextConfiguration>,
+}
+
+impl<'a> From<&'a QueryFormat> for Serialization<'a> {
+    fn from(format: &'a QueryFormat) -> Self {
+        let format = match format {
+            QueryFormat::Delimited(configuration) => Format {
+                format_type: "delimited",
+                delimited_text_configuration: Some(configuration),
+                json_text_configuration: None,
+            },
+            QueryFormat::Json(configuration) => Format {
+                format_type: "json",
+                delimited_text_configuration: None,
+                json_text_configuration: Some(configuration),
+            },
+        };
+        Self { format }
+    }
+}
+
+/// A non-fatal error the service reported while processing the query, such as
+/// a record that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryBlobError {
+    pub name: String,
+    pub description: String,
+    pub position: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryBlobResponse {
+    pub common: CommonStorageResponseHeaders,
+    /// The query result, serialized in the requested output format.
+    pub data: Bytes,
+    /// The number of bytes of the blob the query scanned.
+    pub total_bytes: u64,
+    pub errors: Vec<QueryBlobError>,
+}
+
+impl QueryBlobResponse {
+    pub(crate) fn try_from(headers: &Headers, body: &[u8]) -> azure_core::Result<Self> {
+        let common = CommonStorageResponseHeaders::try_from(headers)?;
+        let QueryResult {
+            data,
+            total_bytes,
+            errors,
+        } = decode_query_result(body)?;
+        Ok(Self {
+            common,
+            data,
+            total_bytes,
+            errors,
+        })
+    }
+}
+
+struct QueryResult {
+    data: Bytes,
+    total_bytes: u64,
+    errors: Vec<QueryBlobError>,
+}
+
+// The response body is an Avro object container file. Each object is a union
+// of the `resultData`, `progress`, `error` and `end` records; the branch order
+// is read from the schema in the file header.
+fn decode_query_result(body: &[u8]) -> azure_core::Result<QueryResult> {
+    let mut reader = AvroReader::new(body);
+
+    if reader.fixed(4)? != b"Obj\x01" {
+        return Err(invalid_avro("missing object container magic"));
+    }
+
+    let mut schema = None;
+    loop {
+        let mut count = reader.long()?;
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            count = -count;
+            reader.long()?;
+        }
+        for _ in 0..count {
+            let key = reader.bytes()?;
+            let value = reader.bytes()?;
+            match key {
+                b"avro.schema" => schema = Some(value),
+                b"avro.codec" if value != b"null" => {
+                    return Err(invalid_avro("unsupported codec"));
+                }
+                _ => {}
+            }
+        }
+    }
+    let branches = union_branches(schema.ok_or_else(|| invalid_avro("missing schema"))?)?;
+    let sync = reader.fixed(16)?;
+
+    let mut data = BytesMut::new();
+    let mut total_bytes = None;
+    let mut errors = Vec::new();
+    while !reader.is_empty() {
+        let count = reader.long()?;
+        reader.long()?;
+        for _ in 0..count {
+            let branch = usize::try_from(reader.long()?)
+                .ok()
+                .and_then(|index| branches.get(index))
+                .ok_or_else(|| invalid_avro("union index out of range"))?;
+            match branch.as_str() {
+                "resultData" => data.extend_from_slice(reader.bytes()?),
+                "progress" => {
+                    reader.long()?;
+                    reader.long()?;
+                }
+                "error" => {
+                    let fatal = reader.boolean()?;
+                    let name = reader.string()?;
+                    let description = reader.string()?;
+                    let position = reader.long()?;
+                    if fatal {
+                        return Err(Error::with_message(ErrorKind::Other, || {
+                            format!(
+                                "blob query failed at position {position}: {name}: {description}"
+                            )
+                        }));
+                    }
+                    errors.push(QueryBlobError {
+                        name,
+                        description,
+                        position,
+                    });
+                }
+                "end" => total_bytes = Some(reader.long()? as u64),
+                other => {
+                    return Err(Error::with_message(ErrorKind::DataConversion, || {
+                        format!("unexpected blob query record: {other}")
+                    }))
+                }
+            }
+        }
+        if reader.fixed(16)? != sync {
+            return Err(invalid_avro("sync marker mismatch"));
+        }
+    }
+
+    Ok(QueryResult {
+        data: data.freeze(),
+        total_bytes: total_bytes.ok_or_else(|| invalid_avro("missing end record"))?,
+        errors,
+    })
+}
+
+fn union_branches(schema: &[u8]) -> azure_core::Result<Vec<String>> {
+    let schema: serde_json::Value = serde_json::from_slice(schema)
+        .map_err(|err| Error::full(ErrorKind::DataConversion, err, "invalid Avro schema"))?;
+    schema
+        .as_array()
+        .ok_or_else(|| invalid_avro("schema is not a union"))?
+        .iter()
+        .map(|branch| {
+            branch["name"]
+                .as_str()
+                .and_then(|name| name.rsplit('.').next())
+                .map(ToOwned::to_owned)
+                .ok_or_else(|| invalid_avro("union branch has no name"))
+        })
+        .collect()
+}
+
+fn invalid_avro(message: &'static str) -> Error {
+    Error::with_message(ErrorKind::DataConversion, || {
+        format!("invalid blob query response: {message}")
+    })
+}
+
+struct AvroReader<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> AvroReader<'a> {
+    fn new(buffer: &'a [u8]) -> Self {
+        Self { buffer }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    fn fixed(&mut self, len: usize) -> azure_core::Result<&'a [u8]> {
+        if self.buffer.len() < len {
+            return Err(invalid_avro("unexpected end of data"));
+        }
+        let (value, rest) = self.buffer.split_at(len);
+        self.buffer = rest;
+        Ok(value)
+    }
+
+    // zig-zag encoded variable length integer
+    fn long(&mut self) -> azure_core::Result<i64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.fixed(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err(invalid_avro("integer overflow"))
+    }
+
+    fn boolean(&mut self) -> azure_core::Result<bool> {
+        Ok(self.fixed(1)?[0] != 0)
+    }
+
+    fn bytes(&mut self) -> azure_core::Result<&'a [u8]> {
+        let len = usize::try_from(self.long()?).map_err(|_| invalid_avro("negative length"))?;
+        self.fixed(len)
+    }
+
+    fn string(&mut self) -> azure_core::Result<String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|_| invalid_avro("invalid UTF-8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"[
+        {"type":"record","name":"com.microsoft.azure.storage.queryBlobContents.resultData","fields":[{"name":"data","type":"bytes"}]},
+        {"type":"record","name":"com.microsoft.azure.storage.queryBlobContents.progress","fields":[{"name":"bytesScanned","type":"long"},{"name":"totalBytes","type":"long"}]},
+        {"type":"record","name":"com.microsoft.azure.storage.queryBlobContents.error","fields":[{"name":"fatal","type":"boolean"},{"name":"name","type":"string"},{"name":"description","type":"string"},{"name":"position","type":"long"}]},
+        {"type":"record","name":"com.microsoft.azure.storage.queryBlobContents.end","fields":[{"name":"totalBytes","type":"long"}]}
+    ]"#;
+    const SYNC: &[u8; 16] = b"0123456789abcdef";
+
+    fn long(out: &mut Vec<u8>, value: i64) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value >= 0x80 {
+            out.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn bytes(out: &mut Vec<u8>, value: &[u8]) {
+        long(out, value.len() as i64);
+        out.extend_from_slice(value);
+    }
+
+    fn container(objects: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = b"Obj\x01".to_vec();
+        long(&mut out, 1);
+        bytes(&mut out, b"avro.schema");
+        bytes(&mut out, SCHEMA.as_bytes());
+        long(&mut out, 0);
+        out.extend_from_slice(SYNC);
+        for object in objects {
+            long(&mut out, 1);
+            long(&mut out, object.len() as i64);
+            out.extend_from_slice(object);
+            out.extend_from_slice(SYNC);
+        }
+        out
+    }
+
+    fn result_data(data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        long(&mut out, 0);
+        bytes(&mut out, data);
+        out
+    }
+
+    fn progress(scanned: i64, total: i64) -> Vec<u8> {
+        let mut out = Vec::new();
+        long(&mut out, 1);
+        long(&mut out, scanned);
+        long(&mut out, total);
+        out
+    }
+
+    fn error(fatal: bool, name: &str, description: &str, position: i64) -> Vec<u8> {
+        let mut out = Vec::new();
+        long(&mut out, 2);
+        out.push(u8::from(fatal));
+        bytes(&mut out, name.as_bytes());
+        bytes(&mut out, description.as_bytes());
+        long(&mut out, position);
+        out
+    }
+
+    fn end(total: i64) -> Vec<u8> {
+        let mut out = Vec::new();
+        long(&mut out, 3);
+        long(&mut out, total);
+        out
+    }
+
+    #[test]
+    fn decode_result() -> azure_core::Result<()> {
+        let body = container(&[
+            result_data(b"a,1\n"),
+            progress(300, 1000),
+            error(false, "InvalidRecord", "bad row", 42),
+            result_data(b"b,2\n"),
+            end(1000),
+        ]);
+
+        let result = decode_query_result(&body)?;
+        assert_eq!(&result.data[..], b"a,1\nb,2\n");
+        assert_eq!(result.total_bytes, 1000);
+        assert_eq!(
+            result.errors,
+            vec![QueryBlobError {
+                name: "InvalidRecord".to_owned(),
+                description: "bad row".to_owned(),
+                position: 42,
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn decode_fatal_error() {
+        let body = container(&[error(true, "ParseError", "bad query", 0), end(0)]);
+        assert!(decode_query_result(&body).is_err());
+    }
+
+    #[test]
+    fn decode_long() -> azure_core::Result<()> {
+        for value in [0, -1, 1, 63, -64, 64, 1 << 40, i64::MIN, i64::MAX] {
+            let mut out = Vec::new();
+            long(&mut out, value);
+            assert_eq!(AvroReader::new(&out).long()?, value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn request_xml() -> azure_core::Result<()> {
+        let csv = QueryFormat::csv();
+        let json = QueryFormat::json();
+        let body = QueryRequest {
+            query_type: "SQL",
+            expression: "SELECT * from BlobStorage",
+            input_serialization: Some(Serialization::from(&csv)),
+            output_serialization: Some(Serialization::from(&json)),
+        }
+        .encode()?;
+        let body = std::str::from_utf8(&body).unwrap();
+
+        assert!(body.contains("<QueryType>SQL</QueryType>"));
+        assert!(body.contains("<Expression>SELECT * from BlobStorage</Expression>"));
+        assert!(body.contains(
+            "<InputSerialization><Format><Type>delimited</Type><DelimitedTextConfiguration>"
+        ));
+        assert!(body.contains("<HasHeaders>true</HasHeaders>"));
+        assert!(
+            body.contains("<OutputSerialization><Format><Type>json</Type><JsonTextConfiguration>")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    SealAppendBlob,
+    client: BlobClient,
+    ?condition_append_position: ConditionAppendPosition,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?lease_id: LeaseId
+}
+
+impl SealAppendBlobBuilder {
+    pub fn into_future(mut self) -> SealAppendBlob {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("comp", "seal");
+
+            let mut headers = Headers::new();
+            headers.add(self.condition_append_position);
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.lease_id);
+
+            let mut request =
+                BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            response.headers().try_into()
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SealAppendBlobResponse {
+    pub etag: String,
+    pub last_modified: OffsetDateTime,
+    pub request_id: RequestId,
+    pub date: OffsetDateTime,
+    pub sealed: bool,
+}
+
+impl TryFrom<&Headers> for SealAppendBlobResponse {
+    type Error = azure_core::Error;
+
+    fn try_from(headers: &Headers) -> azure_core::Result<Self> {
+        Ok(SealAppendBlobResponse {
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            sealed: headers.get_as(&BLOB_SEALED)?,
+        })
+    }
+}
@@ -7,6 +7,7 @@ operation! {
     access_tier: AccessTier,
     ?rehydrate_priority: RehydratePriority,
     ?blob_versioning: BlobVersioning,
+    ?lease_id: LeaseId,
     ?if_tags: IfTags
 }
 
@@ -23,6 +24,7 @@ impl SetBlobTierBuilder {
                 self.rehydrate_priority
                     .unwrap_or(RehydratePriority::Standard),
             );
+            headers.add(self.lease_id);
             headers.add(self.if_tags);
 
             let mut request =
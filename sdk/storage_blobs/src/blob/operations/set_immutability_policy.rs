@@ -0,0 +1,54 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    SetImmutabilityPolicy,
+    client: BlobClient,
+    immutability_policy: ImmutabilityPolicy,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?blob_versioning: BlobVersioning
+}
+
+impl SetImmutabilityPolicyBuilder {
+    pub fn into_future(mut self) -> SetImmutabilityPolicy {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+            url.query_pairs_mut()
+                .append_pair("comp", "immutabilityPolicies");
+            self.blob_versioning.append_to_url_query(&mut url);
+
+            let mut headers = self.immutability_policy.to_headers();
+            headers.add(self.if_modified_since);
+
+            let mut request =
+                BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+            response.headers().try_into()
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SetImmutabilityPolicyResponse {
+    pub request_id: RequestId,
+    pub date: OffsetDateTime,
+    pub until: OffsetDateTime,
+    pub mode: ImmutabilityPolicyMode,
+}
+
+impl TryFrom<&Headers> for SetImmutabilityPolicyResponse {
+    type Error = azure_core::Error;
+
+    fn try_from(headers: &Headers) -> azure_core::Result<Self> {
+        Ok(SetImmutabilityPolicyResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            until: azure_core::date::parse_rfc1123(
+                headers.get_str(&IMMUTABILITY_POLICY_UNTIL_DATE)?,
+            )?,
+            mode: headers.get_as(&IMMUTABILITY_POLICY_MODE)?,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    SetLegalHold,
+    client: BlobClient,
+    legal_hold: bool,
+    ?blob_versioning: BlobVersioning
+}
+
+impl SetLegalHoldBuilder {
+    pub fn into_future(mut self) -> SetLegalHold {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+            url.query_pairs_mut().append_pair("comp", "legalhold");
+            self.blob_versioning.append_to_url_query(&mut url);
+
+            let mut headers = Headers::new();
+            headers.insert(LEGAL_HOLD, self.legal_hold.to_string());
+
+            let mut request =
+                BlobClient::finalize_request(url, azure_core::Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+            response.headers().try_into()
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SetLegalHoldResponse {
+    pub request_id: RequestId,
+    pub date: OffsetDateTime,
+    pub legal_hold: bool,
+}
+
+impl TryFrom<&Headers> for SetLegalHoldResponse {
+    type Error = azure_core::Error;
+
+    fn try_from(headers: &Headers) -> azure_core::Result<Self> {
+        Ok(SetLegalHoldResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            legal_hold: headers.get_as(&LEGAL_HOLD)?,
+        })
+    }
+}
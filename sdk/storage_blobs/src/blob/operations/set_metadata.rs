@@ -44,6 +44,7 @@ pub struct SetMetadataResponse {
     pub etag: String,
     pub server: String,
     pub date: OffsetDateTime,
+    pub version_id: Option<VersionId>,
 }
 
 impl TryFrom<&Headers> for SetMetadataResponse {
@@ -55,6 +56,7 @@ impl TryFrom<&Headers> for SetMetadataResponse {
             etag: etag_from_headers(headers)?,
             server: server_from_headers(headers)?,
             date: date_from_headers(headers)?,
+            version_id: headers.get_optional_as(&VERSION_ID)?,
         })
     }
 }
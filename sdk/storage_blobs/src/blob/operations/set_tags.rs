@@ -17,6 +17,8 @@ operation! {
 impl SetTagsBuilder {
     pub fn into_future(mut self) -> SetTags {
         Box::pin(async move {
+            self.tags.validate()?;
+
             let mut url = self.client.url()?;
 
             url.query_pairs_mut().append_pair("comp", "tags");
@@ -51,6 +51,7 @@ pub struct SnapshotBlobResponse {
     pub date: OffsetDateTime,
     pub snapshot: Snapshot,
     pub last_modified: OffsetDateTime,
+    pub version_id: Option<VersionId>,
 }
 
 impl TryFrom<&Headers> for SnapshotBlobResponse {
@@ -63,6 +64,7 @@ impl TryFrom<&Headers> for SnapshotBlobResponse {
             date: date_from_headers(headers)?,
             snapshot: Snapshot::new(headers.get_str(&SNAPSHOT)?.to_string()),
             last_modified: last_modified_from_headers(headers)?,
+            version_id: headers.get_optional_as(&VERSION_ID)?,
         })
     }
 }
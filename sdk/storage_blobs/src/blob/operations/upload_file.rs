@@ -0,0 +1,11 @@
+use crate::blob::operations::{PutBlockBlobResponse, PutBlockListResponse};
+
+/// The result of `BlobClient::upload_file`.
+///
+/// Small files are uploaded with a single Put Blob request, larger ones are
+/// staged as blocks and committed with Put Block List.
+#[derive(Debug, Clone)]
+pub enum UploadFileResponse {
+    BlockBlob(PutBlockBlobResponse),
+    BlockList(PutBlockListResponse),
+}
@@ -1,18 +1,47 @@
-use crate::{blob::operations::*, prelude::*};
+use crate::{
+    blob::{operations::*, CopyStatus},
+    prelude::*,
+};
+#[cfg(feature = "tokio-fs")]
+use azure_core::tokio::fs::FileStreamBuilder;
 use azure_core::{
-    error::{Error, ErrorKind},
+    error::{Error, ErrorKind, ResultExt},
     headers::Headers,
     prelude::*,
-    Body, Method, Request, Response, StatusCode, Url,
+    Body, ClientOptions, Method, Request, Response, Url,
 };
 use azure_storage::{
+    error::is_not_found,
     prelude::*,
     shared_access_signature::service_sas::{BlobSharedAccessSignature, UserDeligationKey},
-    StorageCredentialsInner,
+    CloudLocation, StorageCredentialsInner, EMULATOR_ACCOUNT,
 };
-use futures::StreamExt;
-use std::ops::Deref;
+use bytes::Bytes;
+use futures::{AsyncRead, AsyncReadExt, StreamExt};
+use percent_encoding::percent_decode_str;
+#[cfg(feature = "tokio-fs")]
+use std::path::Path;
+use std::{collections::HashSet, future::Future, ops::Deref, time::Duration};
 use time::OffsetDateTime;
+use url::Host;
+
+/// The largest block that can be committed to an append blob in one call.
+const MAX_APPEND_BLOCK_SIZE: usize = 4 * 1024 * 1024;
+
+/// How often `rename` checks on a pending copy.
+const RENAME_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Files up to this size are uploaded by `upload_file` in a single request.
+#[cfg(feature = "tokio-fs")]
+const UPLOAD_FILE_SINGLE_SHOT_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// The block size `upload_file` uses for larger files.
+#[cfg(feature = "tokio-fs")]
+const UPLOAD_FILE_BLOCK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// How many blocks `upload_file` stages at a time.
+#[cfg(feature = "tokio-fs")]
+const UPLOAD_FILE_MAX_CONCURRENCY: usize = 4;
 
 /// A client for handling blobs
 ///
@@ -41,15 +70,107 @@ impl BlobClient {
                 || "unable to find blob path",
             ))
         } else {
-            let path = path.join("/");
-            Ok(container_client.blob_client(path))
+            container_client.try_blob_client(path.join("/"))
         }
     }
 
+    /// Create a new `BlobClient` from a full blob URL, such as
+    /// `https://myaccount.blob.core.windows.net/mycontainer/path/to/myblob`.
+    ///
+    /// Unlike `from_sas_url`, the credentials are provided separately. The URL
+    /// must include both a container and a blob name.
+    ///
+    /// Public and China cloud URLs must point at the blob endpoint. URLs whose
+    /// host is an IP address or `localhost`, such as those of the emulator, carry
+    /// the account name as the first path segment. Any other host is treated as
+    /// a custom endpoint, with the account name taken from its first label.
+    pub fn from_url(
+        url: &Url,
+        credentials: impl Into<StorageCredentials>,
+    ) -> azure_core::Result<Self> {
+        let mut segments = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .map(|segment| {
+                        percent_decode_str(segment).decode_utf8().map_err(|_| {
+                            Error::with_message(ErrorKind::DataConversion, || {
+                                format!("invalid percent-encoding in URL path: {url}")
+                            })
+                        })
+                    })
+                    .collect::<azure_core::Result<Vec<_>>>()
+            })
+            .transpose()?
+            .unwrap_or_default()
+            .into_iter();
+
+        let cloud_location = match url.host() {
+            Some(Host::Ipv4(_) | Host::Ipv6(_)) | Some(Host::Domain("localhost")) => {
+                let account = segments.next().ok_or_else(|| {
+                    Error::with_message(ErrorKind::DataConversion, || {
+                        format!("unable to find the account name in URL: {url}")
+                    })
+                })?;
+                match (url.host_str(), url.port_or_known_default()) {
+                    (Some(address), Some(port)) if account == EMULATOR_ACCOUNT => {
+                        CloudLocation::Emulator {
+                            address: address.to_owned(),
+                            port,
+                        }
+                    }
+                    _ => CloudLocation::Custom {
+                        uri: format!("{}/{account}", url.origin().ascii_serialization()),
+                        account: account.into_owned(),
+                    },
+                }
+            }
+            Some(Host::Domain(host)) => match CloudLocation::try_from(url) {
+                Ok(cloud_location) => {
+                    if host.split('.').nth(1) != Some("blob") {
+                        return Err(Error::with_message(ErrorKind::DataConversion, || {
+                            format!("URL does not refer to a blob endpoint: {url}")
+                        }));
+                    }
+                    cloud_location
+                }
+                Err(_) => CloudLocation::Custom {
+                    account: host.split('.').next().unwrap_or_default().to_owned(),
+                    uri: url.origin().ascii_serialization(),
+                },
+            },
+            None => {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!("unable to find the target host in the URL: {url}")
+                }))
+            }
+        };
+
+        let segments: Vec<_> = segments.collect();
+        let (container_name, blob_name) = match segments.split_first() {
+            Some((container_name, blob_name)) if !blob_name.is_empty() => {
+                (container_name.to_string(), blob_name.join("/"))
+            }
+            _ => {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!("unable to find container and blob name in URL: {url}")
+                }))
+            }
+        };
+
+        ClientBuilder::with_location(cloud_location, credentials)
+            .blob_service_client()
+            .try_container_client(container_name)?
+            .try_blob_client(blob_name)
+    }
+
     /// Stream a blob in chunks.
     ///
     /// By default, blobs are downloaded in 1MB chunks to reduce the impact of
-    /// intermittent network issues while downloading large blobs.
+    /// intermittent network issues while downloading large blobs. With
+    /// `resume_on_failure` set, a chunk whose transfer is interrupted is resumed
+    /// from the last byte received rather than failing the download.
     pub fn get(&self) -> GetBlobBuilder {
         GetBlobBuilder::new(self.clone())
     }
@@ -68,6 +189,197 @@ impl BlobClient {
         Ok(blob)
     }
 
+    /// Return an entire blob along with its properties and metadata.
+    ///
+    /// The properties are taken from the download itself, so no separate
+    /// `get_properties` call is needed. If the blob changes while it is being
+    /// downloaded in chunks, an error is returned rather than mixed content.
+    pub async fn get_content_with_properties(&self) -> azure_core::Result<(Vec<u8>, Blob)> {
+        let mut data = Vec::new();
+        let mut properties: Option<Blob> = None;
+        let mut size_known = true;
+        let mut stream = self.get().into_stream();
+        while let Some(value) = stream.next().await {
+            let response = value?;
+            match &properties {
+                Some(blob) if blob.properties.etag != response.blob.properties.etag => {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!(
+                            "blob {} was modified while being downloaded",
+                            self.blob_name
+                        )
+                    }));
+                }
+                Some(_) => {}
+                None => {
+                    let mut blob = response.blob;
+                    // the first chunk only reports its own length
+                    match response.blob_size {
+                        Some(blob_size) => blob.properties.content_length = blob_size,
+                        None => size_known = false,
+                    }
+                    properties = Some(blob);
+                }
+            }
+            data.extend(&response.data.collect().await?);
+        }
+        let mut properties = properties.ok_or_else(|| {
+            Error::with_message(ErrorKind::Other, || {
+                format!("no response received for blob {}", self.blob_name)
+            })
+        })?;
+        // a chunked response is only sized once it has been read
+        if !size_known {
+            properties.properties.content_length = data.len() as u64;
+        }
+        Ok((data, properties))
+    }
+
+    /// Return an entire blob, decompressed according to its `Content-Encoding`.
+    ///
+    /// Blobs stored with a `gzip` or `deflate` content encoding are decoded;
+    /// blobs without a content encoding, or with `identity`, are returned as is.
+    /// Any other content encoding is an error.
+    #[cfg(feature = "decompress")]
+    pub async fn get_content_decompressed(&self) -> azure_core::Result<Vec<u8>> {
+        use std::io::Read;
+
+        let (data, blob) = self.get_content_with_properties().await?;
+        let content_encoding = blob
+            .properties
+            .content_encoding
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let mut decoded = Vec::new();
+        match content_encoding.trim() {
+            "" | "identity" => return Ok(data),
+            "gzip" => flate2::read::MultiGzDecoder::new(&data[..]).read_to_end(&mut decoded),
+            "deflate" => flate2::read::ZlibDecoder::new(&data[..]).read_to_end(&mut decoded),
+            other => {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "unsupported content encoding for blob {}: {other}",
+                        self.blob_name
+                    )
+                }))
+            }
+        }
+        .with_context(ErrorKind::DataConversion, || {
+            format!("failed to decompress blob {}", self.blob_name)
+        })?;
+        Ok(decoded)
+    }
+
+    /// Download the blob to a file on disk, returning the number of bytes written.
+    ///
+    /// Missing parent directories are created. The blob is first written to a
+    /// temporary file next to `path`, which is renamed into place only once the
+    /// whole blob has been downloaded and its length checked, so `path` never
+    /// holds a partial download.
+    ///
+    /// To resume interrupted transfers, use `get().resume_on_failure(true)`
+    /// followed by `GetBlobBuilder::download_to_file` instead.
+    #[cfg(feature = "tokio-fs")]
+    pub async fn download_to_file(&self, path: impl AsRef<Path>) -> azure_core::Result<u64> {
+        self.get().download_to_file(path).await
+    }
+
+    #[cfg(feature = "tokio-fs")]
+    pub(crate) async fn download_blob_to_file(
+        &self,
+        request: GetBlobBuilder,
+        path: &Path,
+    ) -> azure_core::Result<u64> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(ErrorKind::Io, || {
+                    format!("failed to create directory {}", parent.display())
+                })?;
+        }
+
+        let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(format!(".{}.tmp", uuid::Uuid::new_v4()));
+        let temp_path = path.with_file_name(temp_name);
+
+        let written = match self.download_to_temp_file(request, &temp_path).await {
+            Ok(written) => written,
+            Err(err) => {
+                let _ = tokio::fs::remove_file(&temp_path).await;
+                return Err(err);
+            }
+        };
+        tokio::fs::rename(&temp_path, path)
+            .await
+            .with_context(ErrorKind::Io, || {
+                format!("failed to move download into {}", path.display())
+            })?;
+        Ok(written)
+    }
+
+    #[cfg(feature = "tokio-fs")]
+    async fn download_to_temp_file(
+        &self,
+        request: GetBlobBuilder,
+        temp_path: &Path,
+    ) -> azure_core::Result<u64> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut file = tokio::fs::File::create(temp_path)
+            .await
+            .with_context(ErrorKind::Io, || {
+                format!("failed to create {}", temp_path.display())
+            })?;
+
+        let mut written = 0u64;
+        let mut expected: Option<(Option<u64>, azure_core::Etag)> = None;
+        let mut stream = request.into_stream();
+        while let Some(value) = stream.next().await {
+            let response = value?;
+            match &expected {
+                Some((_, etag)) if *etag != response.blob.properties.etag => {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!(
+                            "blob {} was modified while being downloaded",
+                            self.blob_name
+                        )
+                    }));
+                }
+                Some(_) => {}
+                None => expected = Some((response.blob_size, response.blob.properties.etag)),
+            }
+            let mut data = response.data;
+            while let Some(chunk) = data.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk).await?;
+                written += chunk.len() as u64;
+            }
+        }
+        file.flush().await?;
+        file.sync_all().await?;
+
+        // a chunked response does not report its size, so there is nothing to check
+        // the download against
+        let expected = match expected {
+            Some((Some(size), _)) => size,
+            Some((None, _)) => written,
+            None => 0,
+        };
+        if written != expected {
+            return Err(Error::with_message(ErrorKind::Io, || {
+                format!(
+                    "downloaded {written} bytes of blob {}, expected {expected}",
+                    self.blob_name
+                )
+            }));
+        }
+        Ok(written)
+    }
+
     /// Get all user-defined metadata, standard HTTP properties, and system properties for the blob.
     pub fn get_properties(&self) -> GetPropertiesBuilder {
         GetPropertiesBuilder::new(self.clone())
@@ -111,6 +423,12 @@ impl BlobClient {
         PutPageBlobBuilder::new(self.clone(), length)
     }
 
+    /// Grow or shrink an existing page blob to `length` bytes, which must be a
+    /// multiple of 512.
+    pub fn resize_page_blob(&self, length: u128) -> ResizePageBlobBuilder {
+        ResizePageBlobBuilder::new(self.clone(), length)
+    }
+
     /// Creates a new append blob.
     pub fn put_append_blob(&self) -> PutAppendBlobBuilder {
         PutAppendBlobBuilder::new(self.clone())
@@ -126,11 +444,25 @@ impl BlobClient {
         CopyBlobBuilder::new(self.clone(), copy_source)
     }
 
+    /// Start an incremental copy of a page blob snapshot into this blob.
+    ///
+    /// `copy_source` must be the URL of a snapshot of a page blob, accessible
+    /// through a SAS or public access. Only the changes since the snapshot
+    /// last copied to this blob are transferred.
+    pub fn incremental_copy(&self, copy_source: Url) -> IncrementalCopyBlobBuilder {
+        IncrementalCopyBlobBuilder::new(self.clone(), copy_source)
+    }
+
     /// Copy the blob to a destination within the storage account synchronously.
     pub fn copy_from_url(&self, copy_source: Url) -> CopyBlobFromUrlBuilder {
         CopyBlobFromUrlBuilder::new(self.clone(), copy_source)
     }
 
+    /// Abort a pending copy operation, leaving the destination blob with zero length and full metadata.
+    pub fn abort_copy(&self, copy_id: CopyId) -> AbortCopyBlobBuilder {
+        AbortCopyBlobBuilder::new(self.clone(), copy_id)
+    }
+
     /// Create a lease on the blob to lock for write and delete operations.
     pub fn acquire_lease<LD: Into<LeaseDuration>>(
         &self,
@@ -159,6 +491,33 @@ impl BlobClient {
         DeleteBlobVersionBuilder::new(self.clone(), version_id)
     }
 
+    /// Restore the contents and metadata of a soft-deleted blob and any associated soft-deleted snapshots.
+    ///
+    /// ref: <https://docs.microsoft.com/en-us/rest/api/storageservices/undelete-blob>
+    pub fn undelete(&self) -> UndeleteBlobBuilder {
+        UndeleteBlobBuilder::new(self.clone())
+    }
+
+    /// Set a time-based immutability policy on the blob.
+    ///
+    /// The container must have version-level immutability support enabled.
+    pub fn set_immutability_policy(
+        &self,
+        immutability_policy: ImmutabilityPolicy,
+    ) -> SetImmutabilityPolicyBuilder {
+        SetImmutabilityPolicyBuilder::new(self.clone(), immutability_policy)
+    }
+
+    /// Delete the unlocked immutability policy of the blob.
+    pub fn delete_immutability_policy(&self) -> DeleteImmutabilityPolicyBuilder {
+        DeleteImmutabilityPolicyBuilder::new(self.clone())
+    }
+
+    /// Set or clear a legal hold on the blob.
+    pub fn set_legal_hold(&self, legal_hold: bool) -> SetLegalHoldBuilder {
+        SetLegalHoldBuilder::new(self.clone(), legal_hold)
+    }
+
     /* Operations specific to certain blob types */
 
     /// Creates a new block to be committed as part of a block blob.
@@ -201,6 +560,187 @@ impl BlobClient {
         PutBlockListBuilder::new(self.clone(), block_list)
     }
 
+    /// Upload a block blob by splitting `data` into blocks of `block_size`
+    /// bytes, staging up to `max_concurrency` blocks at a time, and then
+    /// committing the block list.
+    ///
+    /// Block ids are derived from the offset of each block within `data`. If
+    /// staging a block fails, the returned error names the failing block id.
+    pub async fn put_block_blob_parallel(
+        &self,
+        data: impl Into<Bytes>,
+        block_size: usize,
+        max_concurrency: usize,
+    ) -> azure_core::Result<PutBlockListResponse> {
+        self.put_blocks_and_commit(data.into(), block_size, max_concurrency, HashSet::new())
+            .await
+    }
+
+    /// Resume an interrupted `put_block_blob_parallel` upload.
+    ///
+    /// Blocks that are already staged with the expected id and size are not
+    /// uploaded again. `data` and `block_size` must be the same as for the
+    /// interrupted upload: block ids only encode offsets, so staged blocks
+    /// holding different data cannot be detected.
+    pub async fn resume_block_blob_parallel(
+        &self,
+        data: impl Into<Bytes>,
+        block_size: usize,
+        max_concurrency: usize,
+    ) -> azure_core::Result<PutBlockListResponse> {
+        let staged = match self
+            .get_block_list()
+            .block_list_type(BlockListType::Uncommitted)
+            .await
+        {
+            Ok(response) => response.block_with_size_list.blocks,
+            Err(err) if is_not_found(&err) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        let staged = staged
+            .into_iter()
+            .filter_map(|block| match block.block_list_type {
+                BlobBlockType::Uncommitted(block_id) => {
+                    Some((block_id.bytes(), block.size_in_bytes))
+                }
+                _ => None,
+            })
+            .collect();
+
+        self.put_blocks_and_commit(data.into(), block_size, max_concurrency, staged)
+            .await
+    }
+
+    async fn put_blocks_and_commit(
+        &self,
+        data: Bytes,
+        block_size: usize,
+        max_concurrency: usize,
+        staged: HashSet<(Bytes, u64)>,
+    ) -> azure_core::Result<PutBlockListResponse> {
+        let block_list = self
+            .stage_blocks(
+                data.len() as u64,
+                block_size as u64,
+                max_concurrency,
+                staged,
+                |start, end| {
+                    let body = data.slice(start as usize..end as usize);
+                    async move { Ok(body.into()) }
+                },
+            )
+            .await?;
+        self.put_block_list(block_list).await
+    }
+
+    /// Stage the blocks of a `len` byte blob, `block_size` bytes at a time,
+    /// and return the block list to commit.
+    ///
+    /// `read_block` is called with the start and end offset of each block
+    /// that is not already in `staged`.
+    async fn stage_blocks<F, Fut>(
+        &self,
+        len: u64,
+        block_size: u64,
+        max_concurrency: usize,
+        staged: HashSet<(Bytes, u64)>,
+        read_block: F,
+    ) -> azure_core::Result<BlockList>
+    where
+        F: Fn(u64, u64) -> Fut,
+        Fut: Future<Output = azure_core::Result<Body>>,
+    {
+        if block_size == 0 || max_concurrency == 0 {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "block_size and max_concurrency must be greater than zero",
+            ));
+        }
+
+        let mut block_list = BlockList::default();
+        let mut blocks = Vec::new();
+        for start in (0..len).step_by(block_size as usize) {
+            let end = u64::min(start + block_size, len);
+            let block_id = format!("{start:032x}");
+            block_list
+                .blocks
+                .push(BlobBlockType::new_uncommitted(block_id.clone()));
+            if !staged.contains(&(Bytes::from(block_id.clone()), end - start)) {
+                blocks.push((block_id, start, end));
+            }
+        }
+
+        let read_block = &read_block;
+        let mut uploads =
+            futures::stream::iter(blocks.into_iter().map(|(block_id, start, end)| async move {
+                let body = read_block(start, end).await?;
+                self.put_block(block_id.clone(), body)
+                    .await
+                    .map_err(|e| e.with_context(|| format!("failed to stage block {block_id}")))
+            }))
+            .buffer_unordered(max_concurrency);
+        while let Some(result) = uploads.next().await {
+            result?;
+        }
+
+        Ok(block_list)
+    }
+
+    /// Upload a file from disk as a block blob.
+    ///
+    /// The content type is guessed from the file extension. Files up to 64MB
+    /// are uploaded in a single request; larger files are staged in 8MB blocks,
+    /// several at a time, and then committed. The response tells which of the
+    /// two happened.
+    #[cfg(feature = "tokio-fs")]
+    pub async fn upload_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> azure_core::Result<UploadFileResponse> {
+        let path = path.as_ref();
+        let content_type = content_type_from_path(path);
+        let open = || async move {
+            tokio::fs::File::open(path)
+                .await
+                .with_context(ErrorKind::Io, || {
+                    format!("failed to open {}", path.display())
+                })
+        };
+        let file = open().await?;
+        let size = file.metadata().await?.len();
+
+        if size <= UPLOAD_FILE_SINGLE_SHOT_LIMIT {
+            let stream = FileStreamBuilder::new(file).build().await?;
+            let response = self
+                .put_block_blob(stream)
+                .content_type(content_type)
+                .await?;
+            return Ok(UploadFileResponse::BlockBlob(response));
+        }
+
+        let block_list = self
+            .stage_blocks(
+                size,
+                UPLOAD_FILE_BLOCK_SIZE,
+                UPLOAD_FILE_MAX_CONCURRENCY,
+                HashSet::new(),
+                |start, end| async move {
+                    let stream = FileStreamBuilder::new(open().await?)
+                        .offset(start)
+                        .block_size(end - start)
+                        .build()
+                        .await?;
+                    Ok(stream.into())
+                },
+            )
+            .await?;
+        let response = self
+            .put_block_list(block_list)
+            .content_type(content_type)
+            .await?;
+        Ok(UploadFileResponse::BlockList(response))
+    }
+
     /// Write a range of pages to a page blob.
     pub fn put_page(&self, ba512_range: BA512Range, content: impl Into<Body>) -> PutPageBuilder {
         PutPageBuilder::new(self.clone(), ba512_range, content.into())
@@ -216,11 +756,64 @@ impl BlobClient {
         AppendBlockBuilder::new(self.clone(), body.into())
     }
 
+    /// Append the contents of `reader` to an existing append blob.
+    ///
+    /// The data is read in chunks of up to 4 MiB, the largest block an append blob
+    /// accepts, and each chunk is committed with its own `append_block` call. Every
+    /// call is conditioned on the expected append position, so a retried request
+    /// fails rather than writing the same chunk twice. This assumes no other writer
+    /// appends to the blob concurrently.
+    ///
+    /// Returns the number of bytes appended.
+    pub async fn append_block_stream<R>(&self, mut reader: R) -> azure_core::Result<u64>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut position = self.get_properties().await?.blob.properties.content_length;
+        let mut appended = 0;
+        let mut buffer = vec![0; MAX_APPEND_BLOCK_SIZE];
+        let mut eof = false;
+        while !eof {
+            let mut filled = 0;
+            while filled < buffer.len() {
+                let read = reader
+                    .read(&mut buffer[filled..])
+                    .await
+                    .context(ErrorKind::Io, "failed to read data to append")?;
+                if read == 0 {
+                    eof = true;
+                    break;
+                }
+                filled += read;
+            }
+            if filled == 0 {
+                break;
+            }
+
+            self.append_block(Bytes::copy_from_slice(&buffer[..filled]))
+                .condition_append_position(position)
+                .await?;
+            position += filled as u64;
+            appended += filled as u64;
+        }
+        Ok(appended)
+    }
+
+    /// Seal an append blob, making it read-only.
+    pub fn seal_append_blob(&self) -> SealAppendBlobBuilder {
+        SealAppendBlobBuilder::new(self.clone())
+    }
+
     /// Clear range of pages in a page blob.
     pub fn clear_page(&self, ba512_range: BA512Range) -> ClearPageBuilder {
         ClearPageBuilder::new(self.clone(), ba512_range)
     }
 
+    /// Create a user delegation shared access signature for the blob, signed
+    /// with a key from `BlobServiceClient::get_user_deligation_key` rather than
+    /// the account key.
+    ///
+    /// The signature expires with the key unless a shorter `expiry` is set on it.
     pub async fn user_delegation_shared_access_signature(
         &self,
         permissions: BlobSasPermissions,
@@ -296,16 +889,140 @@ impl BlobClient {
     pub async fn exists(&self) -> azure_core::Result<bool> {
         match self.get_properties().await {
             Ok(_) => Ok(true),
-            Err(err)
-                if err
-                    .as_http_error()
-                    .map(|e| e.status() == StatusCode::NotFound)
-                    .unwrap_or_default() =>
+            Err(err) if is_not_found(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Wait for a pending copy into this blob to finish, checking its status
+    /// every `poll_interval`.
+    ///
+    /// Returns the blob once its copy status is no longer pending; check
+    /// `copy_status` on its properties to tell success from failure. If
+    /// `timeout` is given and elapses first, an error is returned and the copy
+    /// is left running.
+    pub async fn wait_for_copy(
+        &self,
+        poll_interval: Duration,
+        timeout: Option<Duration>,
+    ) -> azure_core::Result<Blob> {
+        let deadline = timeout.map(|timeout| OffsetDateTime::now_utc() + timeout);
+        loop {
+            let blob = self.get_properties().await?.blob;
+            if blob.properties.copy_status != Some(CopyStatus::Pending) {
+                return Ok(blob);
+            }
+            if let Some(deadline) = deadline {
+                if OffsetDateTime::now_utc() >= deadline {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!(
+                            "timed out waiting for the copy to {} to finish",
+                            self.blob_name
+                        )
+                    }));
+                }
+            }
+            azure_core::sleep::sleep(poll_interval).await;
+        }
+    }
+
+    /// Rename the blob within its container.
+    ///
+    /// Blob storage has no native rename, so this copies the blob to `new_name` on the
+    /// server, waits for the copy to succeed and then deletes the original. If the copy
+    /// fails or is aborted the partially copied blob is removed and the original is left
+    /// untouched. The original is only deleted if it was not modified during the copy.
+    ///
+    /// Snapshots are not copied, so a blob that has snapshots is not renamed and an
+    /// error is returned instead. If the copy is still pending after `timeout`, it is
+    /// left running, an error is returned and the original is kept.
+    pub async fn rename(
+        &self,
+        new_name: impl Into<String>,
+        timeout: Duration,
+    ) -> azure_core::Result<BlobClient> {
+        let destination = self.container_client.blob_client(new_name);
+
+        if self.has_snapshots().await? {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "blob {} has snapshots, which would be lost by renaming it",
+                    self.blob_name
+                )
+            }));
+        }
+
+        let etag = self
+            .get_properties()
+            .await?
+            .blob
+            .properties
+            .etag
+            .to_string();
+
+        let mut source_url = self.url()?;
+        if let StorageCredentialsInner::SASToken(query_pairs) =
+            self.container_client.credentials().0.read().await.deref()
+        {
+            source_url.query_pairs_mut().extend_pairs(query_pairs);
+        }
+
+        let mut copy_status = destination
+            .copy(source_url)
+            .if_source_match(IfSourceMatchCondition::Match(etag.clone()))
+            .await?
+            .copy_status;
+        if copy_status == CopyStatus::Pending {
+            copy_status = destination
+                .wait_for_copy(RENAME_POLL_INTERVAL, Some(timeout))
+                .await?
+                .properties
+                .copy_status
+                .unwrap_or(CopyStatus::Success);
+        }
+
+        if copy_status != CopyStatus::Success {
+            // best effort; the copy failure is the error worth reporting
+            let _ = destination.delete().await;
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "copying {} to {} did not succeed: {copy_status}",
+                    self.blob_name(),
+                    destination.blob_name()
+                )
+            }));
+        }
+
+        self.delete()
+            .if_match(IfMatchCondition::Match(etag))
+            .await?;
+
+        Ok(destination)
+    }
+
+    async fn has_snapshots(&self) -> azure_core::Result<bool> {
+        let mut pages = self
+            .container_client
+            .list_blobs()
+            .prefix(self.blob_name.clone())
+            .include_snapshots(true)
+            .into_stream();
+        while let Some(page) = pages.next().await {
+            if page?
+                .blobs
+                .blobs()
+                .any(|blob| blob.name == self.blob_name && blob.snapshot.is_some())
             {
-                Ok(false)
+                return Ok(true);
             }
-            Err(err) => Err(err),
         }
+        Ok(false)
+    }
+
+    /// Run a SQL query against the contents of a CSV or JSON blob and return only the
+    /// matching records.
+    pub fn query(&self, expression: impl Into<String>) -> QueryBlobBuilder {
+        QueryBlobBuilder::new(self.clone(), expression.into())
     }
 
     /// Create a blob snapshot
@@ -317,6 +1034,16 @@ impl BlobClient {
         &self.blob_name
     }
 
+    /// The name of the container the blob belongs to.
+    pub fn container_name(&self) -> &str {
+        self.container_client.container_name()
+    }
+
+    /// The name of the storage account the blob belongs to.
+    pub fn account(&self) -> &str {
+        self.container_client.account()
+    }
+
     /// Turn into a `BlobLeaseClient`
     pub fn blob_lease_client(&self, lease_id: LeaseId) -> BlobLeaseClient {
         BlobLeaseClient::new(self.clone(), lease_id)
@@ -326,6 +1053,16 @@ impl BlobClient {
         &self.container_client
     }
 
+    /// Create a copy of this client with a new pipeline built from `options`.
+    ///
+    /// See `BlobServiceClient::with_options` for what is kept.
+    #[must_use]
+    pub fn with_options(&self, options: impl Into<ClientOptions>) -> Self {
+        self.container_client
+            .with_options(options)
+            .blob_client(self.blob_name.clone())
+    }
+
     /// Full URL for the blob.
     pub fn url(&self) -> azure_core::Result<Url> {
         let mut url = self.container_client().url()?;
@@ -336,6 +1073,17 @@ impl BlobClient {
         Ok(url)
     }
 
+    /// Send a request built by the caller through this client's pipeline.
+    ///
+    /// See `BlobServiceClient::send_request`.
+    pub async fn send_request(
+        &self,
+        context: &mut Context,
+        request: &mut Request,
+    ) -> azure_core::Result<Response> {
+        self.container_client.send_request(context, request).await
+    }
+
     pub(crate) fn finalize_request(
         url: Url,
         method: Method,
@@ -354,6 +1102,43 @@ impl BlobClient {
     }
 }
 
+/// Guess a blob content type from a file extension, falling back to
+/// `application/octet-stream`.
+#[cfg(feature = "tokio-fs")]
+fn content_type_from_path(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "txt" | "log" => "text/plain",
+        "htm" | "html" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "js" => "text/javascript",
+        "md" => "text/markdown",
+        "xml" => "application/xml",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "wasm" => "application/wasm",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "ico" => "image/x-icon",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        _ => "application/octet-stream",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,6 +1183,12 @@ mod tests {
         let url = Url::parse("https://accountname.blob.core.windows.net/?token=1")?;
         assert!(BlobClient::from_sas_url(&url).is_err(), "missing container");
 
+        let url = Url::parse("https://accountname.blob.core.windows.net/My_Container/b?token=1")?;
+        assert!(
+            BlobClient::from_sas_url(&url).is_err(),
+            "invalid container name"
+        );
+
         let example =
             format!("https://{account}.blob.core.chinacloudapi.cn/{container}/{path}?token=1");
         let url = Url::parse(&example)?;
@@ -409,6 +1200,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_url_with_credentials() -> azure_core::Result<()> {
+        let url = Url::parse(
+            "https://accountname.blob.core.windows.net/mycontainer/nested/my%20blob.txt",
+        )?;
+        let blob_client = BlobClient::from_url(&url, StorageCredentials::anonymous())?;
+        assert_eq!(blob_client.account(), "accountname");
+        assert_eq!(blob_client.container_name(), "mycontainer");
+        assert_eq!(blob_client.blob_name(), "nested/my blob.txt");
+        assert_eq!(blob_client.url()?, url);
+
+        let url = Url::parse("https://accountname.queue.core.windows.net/mycontainer/myblob")?;
+        assert!(
+            BlobClient::from_url(&url, StorageCredentials::anonymous()).is_err(),
+            "not a blob endpoint"
+        );
+
+        let url = Url::parse("https://accountname.blob.core.windows.net/mycontainer")?;
+        assert!(
+            BlobClient::from_url(&url, StorageCredentials::anonymous()).is_err(),
+            "missing blob name"
+        );
+
+        let url = Url::parse("http://127.0.0.1:10000/devstoreaccount1/mycontainer/my%20blob")?;
+        let blob_client = BlobClient::from_url(&url, StorageCredentials::emulator())?;
+        assert_eq!(blob_client.account(), EMULATOR_ACCOUNT);
+        assert_eq!(blob_client.container_name(), "mycontainer");
+        assert_eq!(blob_client.blob_name(), "my blob");
+        assert_eq!(blob_client.url()?, url);
+
+        let url = Url::parse("http://localhost:10000/otheraccount/mycontainer/myblob")?;
+        let blob_client = BlobClient::from_url(&url, StorageCredentials::anonymous())?;
+        assert_eq!(blob_client.account(), "otheraccount");
+        assert_eq!(blob_client.url()?, url);
+
+        let url = Url::parse("https://accountname.blob.core.usgovcloudapi.net/mycontainer/myblob")?;
+        let blob_client = BlobClient::from_url(&url, StorageCredentials::anonymous())?;
+        assert_eq!(blob_client.account(), "accountname");
+        assert_eq!(blob_client.url()?, url);
+
+        let url = Url::parse("https://accountname.blob.core.windows.net/mycontainer/bad%FF")?;
+        assert!(
+            BlobClient::from_url(&url, StorageCredentials::anonymous()).is_err(),
+            "invalid utf-8 in blob name"
+        );
+
+        let url = Url::parse("https://accountname.blob.core.windows.net/My_Container/myblob")?;
+        assert!(
+            BlobClient::from_url(&url, StorageCredentials::anonymous()).is_err(),
+            "invalid container name"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio-fs")]
+    #[test]
+    fn test_content_type_from_path() {
+        assert_eq!(content_type_from_path(Path::new("a/b.txt")), "text/plain");
+        assert_eq!(content_type_from_path(Path::new("photo.JPG")), "image/jpeg");
+        assert_eq!(
+            content_type_from_path(Path::new("archive.tar.gz")),
+            "application/gzip"
+        );
+        assert_eq!(
+            content_type_from_path(Path::new("no_extension")),
+            "application/octet-stream"
+        );
+    }
+
     struct FakeSas {
         token: String,
     }
@@ -1,17 +1,25 @@
 use crate::{
     clients::{BlobClient, BlobLeaseClient, ContainerClient, ContainerLeaseClient},
+    container::validate_container_name,
+    options::BlobBatch,
     service::operations::*,
 };
 use azure_core::{
-    headers::Headers, request_options::LeaseId, Body, ClientOptions, Context, Method, Pipeline,
-    Request, Response, Url,
+    headers::Headers,
+    request_options::{LeaseId, Timeout},
+    Body, ClientOptions, Context, Method, Pipeline, Policy, Request, Response, TelemetryOptions,
+    TimeoutPolicy, Url,
 };
 use azure_storage::{
-    clients::{new_pipeline_from_options, shared_access_signature, ServiceType},
+    clients::{
+        add_storage_headers, new_pipeline_from_options, new_pipeline_from_options_with_token_scope,
+        shared_access_signature, ServiceType,
+    },
     prelude::{AccountSasPermissions, AccountSasResource, AccountSasResourceType},
     shared_access_signature::account_sas::AccountSharedAccessSignature,
-    CloudLocation, StorageCredentials,
+    CloudLocation, HttpsOnlyPolicy, LoggingPolicy, SecondaryReadPolicy, StorageCredentials,
 };
+use std::sync::Arc;
 use time::OffsetDateTime;
 
 /// A builder for the blob service client.
@@ -20,6 +28,10 @@ pub struct ClientBuilder {
     cloud_location: CloudLocation,
     options: ClientOptions,
     credentials: StorageCredentials,
+    token_scope: Option<String>,
+    application_id: Option<String>,
+    secondary_read_fallback: bool,
+    https_only: bool,
 }
 
 impl ClientBuilder {
@@ -48,6 +60,10 @@ impl ClientBuilder {
             options: ClientOptions::default(),
             cloud_location,
             credentials: credentials.into(),
+            token_scope: None,
+            application_id: None,
+            secondary_read_fallback: false,
+            https_only: false,
         }
     }
 
@@ -66,16 +82,55 @@ impl ClientBuilder {
     /// Convert the builder into a `BlobServiceClient` instance.
     #[must_use]
     pub fn blob_service_client(self) -> BlobServiceClient {
+        let builder = self.clone();
         let Self {
             cloud_location,
-            options,
+            mut options,
             credentials,
+            token_scope,
+            application_id,
+            secondary_read_fallback,
+            https_only,
         } = self;
 
+        if let Some(application_id) = application_id {
+            options = options.telemetry(TelemetryOptions::default().application_id(application_id));
+        }
+
+        if https_only && !matches!(cloud_location, CloudLocation::Emulator { .. }) {
+            options
+                .per_call_policies_mut()
+                .push(Arc::new(HttpsOnlyPolicy::new()));
+        }
+
+        if secondary_read_fallback {
+            if let (Ok(primary), Ok(secondary)) = (
+                cloud_location.url(ServiceType::Blob),
+                cloud_location.secondary_url(ServiceType::Blob),
+            ) {
+                if let (Some(primary), Some(secondary)) = (primary.host_str(), secondary.host_str())
+                {
+                    options
+                        .per_call_policies_mut()
+                        .push(Arc::new(SecondaryReadPolicy::new(primary, secondary)));
+                }
+            }
+        }
+
+        let pipeline = match &token_scope {
+            Some(token_scope) => new_pipeline_from_options_with_token_scope(
+                options,
+                credentials.clone(),
+                token_scope.clone(),
+            ),
+            None => new_pipeline_from_options(options, credentials.clone()),
+        };
+
         BlobServiceClient {
-            pipeline: new_pipeline_from_options(options, credentials.clone()),
+            pipeline,
             cloud_location,
             credentials,
+            builder,
         }
     }
 
@@ -130,6 +185,50 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the scope requested for bearer tokens when authenticating with a `TokenCredential`.
+    ///
+    /// Defaults to `https://storage.azure.com/.default`. An audience such as
+    /// `https://myaccount.blob.core.windows.net` is also accepted and is turned
+    /// into the matching `/.default` scope.
+    #[must_use]
+    pub fn token_scope(mut self, token_scope: impl Into<String>) -> Self {
+        let token_scope = token_scope.into();
+        self.token_scope = Some(if token_scope.ends_with("/.default") {
+            token_scope
+        } else {
+            format!("{}/.default", token_scope.trim_end_matches('/'))
+        });
+        self
+    }
+
+    /// Retry reads that fail on the primary endpoint against the secondary
+    /// endpoint of a read-access geo-redundant (RA-GRS) account.
+    ///
+    /// Only the Public and China cloud locations have a well-known secondary
+    /// endpoint. Enabling the fallback for any other cloud location is an
+    /// error.
+    pub fn secondary_read_fallback(
+        mut self,
+        secondary_read_fallback: bool,
+    ) -> azure_core::Result<Self> {
+        if secondary_read_fallback {
+            self.cloud_location.secondary_url(ServiceType::Blob)?;
+        }
+        self.secondary_read_fallback = secondary_read_fallback;
+        Ok(self)
+    }
+
+    /// Refuse to send requests over plaintext HTTP.
+    ///
+    /// This has no effect for the emulator, which only listens on HTTP. A
+    /// minimum TLS version is a setting of the HTTP client, and can be set on
+    /// the client passed to `transport`.
+    #[must_use]
+    pub fn https_only(mut self, https_only: bool) -> Self {
+        self.https_only = https_only;
+        self
+    }
+
     /// Set the retry options.
     #[must_use]
     pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
@@ -144,6 +243,51 @@ impl ClientBuilder {
         self
     }
 
+    /// Set an application ID to prefix the `User-Agent` header with.
+    #[must_use]
+    pub fn application_id(mut self, application_id: impl Into<String>) -> Self {
+        self.application_id = Some(application_id.into());
+        self
+    }
+
+    /// Set the default server-side timeout for each request.
+    ///
+    /// This is sent as the `timeout` query parameter. It can be overridden for a single
+    /// operation by inserting a `Timeout` into the operation's `Context`.
+    #[must_use]
+    pub fn timeout(mut self, timeout: impl Into<Timeout>) -> Self {
+        self.options = self
+            .options
+            .timeout(TimeoutPolicy::new(Some(timeout.into())));
+        self
+    }
+
+    /// Add a policy that is run once per call, before any retries.
+    #[must_use]
+    pub fn per_call_policy(mut self, policy: Arc<dyn Policy>) -> Self {
+        self.options.per_call_policies_mut().push(policy);
+        self
+    }
+
+    /// Add a policy that is run on every attempt, including retries.
+    ///
+    /// Per-retry policies run before the request is signed, so any headers
+    /// they add are covered by the authorization.
+    #[must_use]
+    pub fn per_retry_policy(mut self, policy: Arc<dyn Policy>) -> Self {
+        self.options.per_retry_policies_mut().push(policy);
+        self
+    }
+
+    /// Log every request attempt at `level`, with its method, URL, status,
+    /// duration and request id.
+    ///
+    /// Headers are not logged and SAS signatures are redacted from the URL.
+    #[must_use]
+    pub fn logging(self, level: tracing::Level) -> Self {
+        self.per_retry_policy(Arc::new(LoggingPolicy::new(level)))
+    }
+
     /// Override all of the client options.
     ///
     /// *Warning!*: This overrides all client options that have been previously set on this builder.
@@ -155,11 +299,16 @@ impl ClientBuilder {
 }
 
 /// A client for interacting with the blob storage service.
+///
+/// All clients are `Send + Sync` and cheap to clone; clones share the same
+/// pipeline and credentials, so one client can be used from many tasks.
 #[derive(Debug, Clone)]
 pub struct BlobServiceClient {
     pipeline: Pipeline,
     cloud_location: CloudLocation,
     credentials: StorageCredentials,
+    /// The configuration the client was built from, replayed by `with_options`.
+    builder: ClientBuilder,
 }
 
 impl BlobServiceClient {
@@ -182,6 +331,29 @@ impl BlobServiceClient {
         self.cloud_location.account()
     }
 
+    /// Create a copy of this client with a new pipeline built from `options`.
+    ///
+    /// The retry, transport and timeout options are taken from `options`.
+    /// Everything else the client was built with is kept: the cloud location,
+    /// credentials, token scope, application id, `https_only`,
+    /// `secondary_read_fallback`, `logging` and any policies added to the
+    /// `ClientBuilder`. Policies in `options` are run after those.
+    #[must_use]
+    pub fn with_options(&self, options: impl Into<ClientOptions>) -> Self {
+        let mut builder = self.builder.clone();
+        let mut options = options.into();
+        let per_call_policies = std::mem::take(builder.options.per_call_policies_mut());
+        options
+            .per_call_policies_mut()
+            .splice(0..0, per_call_policies);
+        let per_retry_policies = std::mem::take(builder.options.per_retry_policies_mut());
+        options
+            .per_retry_policies_mut()
+            .splice(0..0, per_retry_policies);
+        builder.options = options;
+        builder.blob_service_client()
+    }
+
     /// Get information about the blob storage account
     pub fn get_account_information(&self) -> GetAccountInformationBuilder {
         GetAccountInformationBuilder::new(self.clone())
@@ -197,6 +369,11 @@ impl BlobServiceClient {
         ListContainersBuilder::new(self.clone())
     }
 
+    /// Send a batch of delete and set tier operations in a single request.
+    pub fn submit_batch(&self, batch: BlobBatch) -> SubmitBatchBuilder {
+        SubmitBatchBuilder::new(self.clone(), batch)
+    }
+
     pub fn get_properties(&self) -> GetBlobServicePropertiesBuilder {
         GetBlobServicePropertiesBuilder::new(self.clone())
     }
@@ -209,6 +386,17 @@ impl BlobServiceClient {
         ContainerClient::new(self.clone(), container_name.into())
     }
 
+    /// Like `container_client`, but fails if `container_name` is not a valid
+    /// container name.
+    pub fn try_container_client<S: Into<String>>(
+        &self,
+        container_name: S,
+    ) -> azure_core::Result<ContainerClient> {
+        let container_name = container_name.into();
+        validate_container_name(&container_name)?;
+        Ok(ContainerClient::new(self.clone(), container_name))
+    }
+
     pub fn get_user_deligation_key(
         &self,
         start: OffsetDateTime,
@@ -240,10 +428,30 @@ impl BlobServiceClient {
         self.credentials.replace(new_credentials).await
     }
 
+    /// Send a request built by the caller through this client's pipeline.
+    ///
+    /// This is an escape hatch for operations the crate does not cover yet. The
+    /// request is authorized and retried like any other, and gets the same
+    /// `x-ms-date` and `x-ms-version` headers, plus `Content-Length` if it has a
+    /// body. The URL, method, remaining headers and body are left to the caller.
+    pub async fn send_request(
+        &self,
+        context: &mut Context,
+        request: &mut Request,
+    ) -> azure_core::Result<Response> {
+        add_storage_headers(request);
+        self.send(context, request).await
+    }
+
     pub(crate) fn credentials(&self) -> &StorageCredentials {
         &self.credentials
     }
 
+    /// The scope requested for bearer tokens, if not the default storage scope.
+    pub(crate) fn token_scope(&self) -> Option<&str> {
+        self.builder.token_scope.as_deref()
+    }
+
     pub(crate) fn finalize_request(
         url: Url,
         method: Method,
@@ -270,6 +478,122 @@ mod tests {
     use azure_storage::StorageCredentialsInner;
     use std::ops::Deref;
 
+    #[test]
+    fn clients_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<BlobServiceClient>();
+        assert_send_sync::<ContainerClient>();
+        assert_send_sync::<ContainerLeaseClient>();
+        assert_send_sync::<BlobClient>();
+        assert_send_sync::<BlobLeaseClient>();
+    }
+
+    #[test]
+    fn token_scope_from_audience() {
+        let builder = ClientBuilder::new("test", StorageCredentials::anonymous());
+
+        let scoped = builder
+            .clone()
+            .token_scope("https://storage.azure.com/.default");
+        assert_eq!(
+            scoped.token_scope.as_deref(),
+            Some("https://storage.azure.com/.default")
+        );
+
+        let audience = builder.token_scope("https://test.blob.core.windows.net/");
+        assert_eq!(
+            audience.token_scope.as_deref(),
+            Some("https://test.blob.core.windows.net/.default")
+        );
+    }
+
+    #[test]
+    fn try_clients_validate_names() {
+        let service =
+            ClientBuilder::new("test", StorageCredentials::anonymous()).blob_service_client();
+        assert!(service.try_container_client("my_container").is_err());
+
+        let container = service.try_container_client("container").unwrap();
+        assert!(container.try_blob_client("").is_err());
+        assert_eq!(container.try_blob_client("a/b").unwrap().blob_name(), "a/b");
+    }
+
+    #[test]
+    fn secondary_read_fallback_requires_secondary_endpoint() {
+        assert!(ClientBuilder::new("test", StorageCredentials::anonymous())
+            .secondary_read_fallback(true)
+            .is_ok());
+        assert!(ClientBuilder::emulator()
+            .secondary_read_fallback(true)
+            .is_err());
+        assert!(ClientBuilder::emulator()
+            .secondary_read_fallback(false)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn https_only_rejects_http() {
+        let cloud_location = CloudLocation::Custom {
+            account: "test".to_owned(),
+            uri: "http://127.0.0.1:1/test".to_owned(),
+        };
+        let blob_client =
+            ClientBuilder::with_location(cloud_location, StorageCredentials::anonymous())
+                .https_only(true)
+                .blob_client("container", "blob");
+
+        let err = blob_client
+            .get_properties()
+            .await
+            .expect_err("plaintext request should be rejected");
+        assert!(err
+            .to_string()
+            .contains("refusing to send a request over http"));
+    }
+
+    #[test]
+    fn with_options_keeps_location_and_scope() {
+        let blob_client = ClientBuilder::new("test", StorageCredentials::anonymous())
+            .token_scope("https://test.blob.core.windows.net")
+            .blob_client("container", "blob");
+
+        let rebuilt = blob_client.with_options(ClientOptions::default());
+        assert_eq!(rebuilt.account(), "test");
+        assert_eq!(rebuilt.container_name(), "container");
+        assert_eq!(rebuilt.blob_name(), "blob");
+        assert_eq!(
+            rebuilt.container_client().service_client().token_scope(),
+            Some("https://test.blob.core.windows.net/.default")
+        );
+    }
+
+    #[tokio::test]
+    async fn with_options_keeps_builder_policies() {
+        let cloud_location = CloudLocation::Custom {
+            account: "test".to_owned(),
+            uri: "http://127.0.0.1:1/test".to_owned(),
+        };
+        let blob_client =
+            ClientBuilder::with_location(cloud_location, StorageCredentials::anonymous())
+                .https_only(true)
+                .application_id("app")
+                .blob_client("container", "blob");
+
+        let rebuilt = blob_client.with_options(ClientOptions::default());
+        let builder = &rebuilt.container_client().service_client().builder;
+        assert!(builder.https_only);
+        assert_eq!(builder.application_id.as_deref(), Some("app"));
+
+        let err = rebuilt
+            .get_properties()
+            .await
+            .expect_err("plaintext request should be rejected");
+        assert!(err
+            .to_string()
+            .contains("refusing to send a request over http"));
+    }
+
     #[tokio::test]
     async fn update_credentials() -> azure_core::Result<()> {
         let account = "test";
@@ -1,11 +1,17 @@
-use crate::{clients::*, container::operations::*, prelude::PublicAccess};
+use crate::{
+    blob::validate_blob_name,
+    clients::*,
+    container::{is_container_already_exists, operations::*},
+    prelude::PublicAccess,
+};
 use azure_core::{
     error::{Error, ErrorKind},
     headers::Headers,
     prelude::*,
-    Body, Method, Request, Response, StatusCode, Url,
+    Body, ClientOptions, Method, Request, Response, Url,
 };
 use azure_storage::{
+    error::is_not_found,
     prelude::BlobSasPermissions,
     shared_access_signature::{
         service_sas::{BlobSharedAccessSignature, BlobSignedResource, UserDeligationKey},
@@ -41,12 +47,15 @@ impl ContainerClient {
             })
         })?;
 
-        let client =
-            ClientBuilder::with_location(cloud_location, credentials).container_client(container);
-        Ok(client)
+        ClientBuilder::with_location(cloud_location, credentials)
+            .blob_service_client()
+            .try_container_client(container)
     }
 
     /// Create a container
+    ///
+    /// `deny_encryption_scope_override` can only be set together with
+    /// `default_encryption_scope`.
     pub fn create(&self) -> CreateBuilder {
         CreateBuilder::new(self.clone())
     }
@@ -71,11 +80,42 @@ impl ContainerClient {
         GetPropertiesBuilder::new(self.clone())
     }
 
+    /// Get all user-defined metadata for the container.
+    pub fn get_metadata(&self) -> GetMetadataBuilder {
+        GetMetadataBuilder::new(self.clone())
+    }
+
+    /// Set all user-defined metadata of the container.
+    ///
+    /// Any metadata not passed is removed from the container.
+    pub fn set_metadata(&self) -> SetMetadataBuilder {
+        SetMetadataBuilder::new(self.clone())
+    }
+
+    /// Get all the blobs in the container with the given tags in the where expression
+    pub fn find_blobs_by_tags(&self, expression: String) -> FindBlobsByTagsBuilder {
+        FindBlobsByTagsBuilder::new(self.clone(), expression)
+    }
+
     /// List the blobs in a container
     pub fn list_blobs(&self) -> ListBlobsBuilder {
         ListBlobsBuilder::new(self.clone())
     }
 
+    /// List the blobs under `prefix` grouped by virtual directory, using `/`
+    /// as the delimiter.
+    ///
+    /// Pass an empty prefix to start at the root of the container. When
+    /// `recursive` is set every subdirectory is listed as well, otherwise only
+    /// the prefixes directly below `prefix` are returned, without contents.
+    pub async fn list_blob_tree(
+        &self,
+        prefix: impl Into<String>,
+        recursive: bool,
+    ) -> azure_core::Result<BlobDirectory> {
+        BlobDirectory::list(self.clone(), prefix.into(), recursive).await
+    }
+
     /// Acquite a lease on a container
     pub fn acquire_lease<LD: Into<LeaseDuration>>(
         &self,
@@ -93,14 +133,18 @@ impl ContainerClient {
     pub async fn exists(&self) -> azure_core::Result<bool> {
         match self.get_properties().await {
             Ok(_) => Ok(true),
-            Err(err)
-                if err
-                    .as_http_error()
-                    .map(|e| e.status() == StatusCode::NotFound)
-                    .unwrap_or_default() =>
-            {
-                Ok(false)
-            }
+            Err(err) if is_not_found(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Create the container unless it already exists.
+    ///
+    /// Returns `true` if the container was created by this call.
+    pub async fn create_if_not_exists(&self) -> azure_core::Result<bool> {
+        match self.create().await {
+            Ok(_) => Ok(true),
+            Err(err) if is_container_already_exists(&err) => Ok(false),
             Err(err) => Err(err),
         }
     }
@@ -113,14 +157,44 @@ impl ContainerClient {
         BlobClient::new(self.clone(), blob_name.into())
     }
 
+    /// Like `blob_client`, but fails if `blob_name` is not a valid blob name.
+    pub fn try_blob_client<BN: Into<String>>(
+        &self,
+        blob_name: BN,
+    ) -> azure_core::Result<BlobClient> {
+        let blob_name = blob_name.into();
+        validate_blob_name(&blob_name)?;
+        Ok(BlobClient::new(self.clone(), blob_name))
+    }
+
     pub fn service_client(&self) -> BlobServiceClient {
         self.service_client.clone()
     }
 
+    /// Create a copy of this client with a new pipeline built from `options`.
+    ///
+    /// See `BlobServiceClient::with_options` for what is kept.
+    #[must_use]
+    pub fn with_options(&self, options: impl Into<ClientOptions>) -> Self {
+        self.service_client
+            .with_options(options)
+            .container_client(self.container_name.clone())
+    }
+
     pub fn container_name(&self) -> &str {
         &self.container_name
     }
 
+    /// The name of the storage account the container belongs to.
+    pub fn account(&self) -> &str {
+        self.service_client.account()
+    }
+
+    /// Create a user delegation shared access signature for the container, signed
+    /// with a key from `BlobServiceClient::get_user_deligation_key` rather than
+    /// the account key.
+    ///
+    /// The signature expires with the key unless a shorter `expiry` is set on it.
     pub async fn user_delegation_shared_access_signature(
         &self,
         permissions: BlobSasPermissions,
@@ -194,6 +268,17 @@ impl ContainerClient {
         self.service_client.credentials()
     }
 
+    /// Send a request built by the caller through this client's pipeline.
+    ///
+    /// See `BlobServiceClient::send_request`.
+    pub async fn send_request(
+        &self,
+        context: &mut Context,
+        request: &mut Request,
+    ) -> azure_core::Result<Response> {
+        self.service_client.send_request(context, request).await
+    }
+
     pub(crate) async fn send(
         &self,
         context: &mut Context,
@@ -237,6 +322,34 @@ mod integration_tests {
             .expect("delete container should succeed");
     }
 
+    #[tokio::test]
+    async fn test_create_if_not_exists() {
+        let container_name = uuid::Uuid::new_v4().to_string();
+        let container_client = get_emulator_client(&container_name);
+
+        assert!(!container_client
+            .exists()
+            .await
+            .expect("exists should succeed"));
+        assert!(container_client
+            .create_if_not_exists()
+            .await
+            .expect("first create_if_not_exists should succeed"));
+        assert!(!container_client
+            .create_if_not_exists()
+            .await
+            .expect("second create_if_not_exists should succeed"));
+        assert!(container_client
+            .exists()
+            .await
+            .expect("exists should succeed"));
+
+        container_client
+            .delete()
+            .await
+            .expect("delete container should succeed");
+    }
+
     #[tokio::test]
     async fn test_list_blobs() {
         let container_name = uuid::Uuid::new_v4().to_string();
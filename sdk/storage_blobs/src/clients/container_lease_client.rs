@@ -15,6 +15,10 @@ impl ContainerLeaseClient {
         }
     }
 
+    pub fn change(&self, proposed_lease_id: ProposedLeaseId) -> ChangeLeaseBuilder {
+        ChangeLeaseBuilder::new(self.clone(), proposed_lease_id)
+    }
+
     pub fn release(&self) -> ReleaseLeaseBuilder {
         ReleaseLeaseBuilder::new(self.clone())
     }
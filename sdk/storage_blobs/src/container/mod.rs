@@ -187,3 +187,83 @@ impl Container {
         })
     }
 }
+
+const MIN_CONTAINER_NAME_LEN: usize = 3;
+const MAX_CONTAINER_NAME_LEN: usize = 63;
+
+/// Check a container name against the service's naming rules.
+///
+/// Names must be 3 to 63 characters long, contain only lowercase letters,
+/// numbers and dashes, start and end with a letter or number, and must not
+/// contain consecutive dashes. The reserved `$root`, `$logs` and `$web`
+/// containers are also accepted.
+///
+/// ref: <https://docs.microsoft.com/en-us/rest/api/storageservices/naming-and-referencing-containers--blobs--and-metadata#container-names>
+pub fn validate_container_name(name: &str) -> azure_core::Result<()> {
+    if matches!(name, "$root" | "$logs" | "$web") {
+        return Ok(());
+    }
+
+    if !(MIN_CONTAINER_NAME_LEN..=MAX_CONTAINER_NAME_LEN).contains(&name.len()) {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!(
+                "container name must be between {MIN_CONTAINER_NAME_LEN} and {MAX_CONTAINER_NAME_LEN} characters: {name:?}"
+            )
+        }));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!(
+                "container name may only contain lowercase letters, numbers and dashes: {name:?}"
+            )
+        }));
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!("container name must start and end with a letter or number: {name:?}")
+        }));
+    }
+    if name.contains("--") {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!("container name must not contain consecutive dashes: {name:?}")
+        }));
+    }
+
+    Ok(())
+}
+
+/// Whether `err` is the service rejecting a create because the container
+/// already exists.
+pub fn is_container_already_exists(err: &Error) -> bool {
+    azure_storage::error::is_conflict(err)
+        && err
+            .as_http_error()
+            .map(|e| e.error_code() == Some("ContainerAlreadyExists"))
+            .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_container_name() {
+        assert!(validate_container_name("abc").is_ok());
+        assert!(validate_container_name("my-container-01").is_ok());
+        assert!(validate_container_name(&"a".repeat(63)).is_ok());
+        assert!(validate_container_name("$root").is_ok());
+        assert!(validate_container_name("$web").is_ok());
+
+        assert!(validate_container_name("ab").is_err());
+        assert!(validate_container_name(&"a".repeat(64)).is_err());
+        assert!(validate_container_name("MyContainer").is_err());
+        assert!(validate_container_name("my_container").is_err());
+        assert!(validate_container_name("-container").is_err());
+        assert!(validate_container_name("container-").is_err());
+        assert!(validate_container_name("my--container").is_err());
+        assert!(validate_container_name("$other").is_err());
+    }
+}
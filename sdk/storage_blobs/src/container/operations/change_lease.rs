@@ -0,0 +1,36 @@
+use crate::{container::operations::AcquireLeaseResponse, prelude::*};
+use azure_core::Method;
+use azure_core::{headers::*, prelude::*};
+
+pub type ChangeLeaseResponse = AcquireLeaseResponse;
+
+operation! {
+    ChangeLease,
+    client: ContainerLeaseClient,
+    proposed_lease_id: ProposedLeaseId,
+    ?if_modified_since: IfModifiedSinceCondition
+}
+
+impl ChangeLeaseBuilder {
+    pub fn into_future(mut self) -> ChangeLease {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("restype", "container");
+            url.query_pairs_mut().append_pair("comp", "lease");
+
+            let mut headers = Headers::new();
+            headers.insert(LEASE_ACTION, "change");
+            headers.add(self.client.lease_id());
+            headers.add(self.proposed_lease_id);
+            headers.add(self.if_modified_since);
+
+            let mut request =
+                ContainerLeaseClient::finalize_request(url, Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            ChangeLeaseResponse::from_headers(response.headers())
+        })
+    }
+}
@@ -1,12 +1,19 @@
 use crate::prelude::*;
 use azure_core::Method;
-use azure_core::{headers::AsHeaders, headers::Headers, prelude::*};
+use azure_core::{
+    error::{Error, ErrorKind},
+    headers::AsHeaders,
+    headers::Headers,
+    prelude::*,
+};
 
 operation! {
     Create,
     client: ContainerClient,
     ?public_access: PublicAccess,
-    ?metadata: Metadata
+    ?metadata: Metadata,
+    ?default_encryption_scope: String,
+    ?deny_encryption_scope_override: bool
 }
 
 impl CreateBuilder {
@@ -31,6 +38,24 @@ impl CreateBuilder {
                 headers.insert(name, value);
             }
 
+            if self.default_encryption_scope.is_none()
+                && self.deny_encryption_scope_override.is_some()
+            {
+                return Err(Error::message(
+                    ErrorKind::Other,
+                    "deny_encryption_scope_override requires default_encryption_scope",
+                ));
+            }
+            if let Some(default_encryption_scope) = self.default_encryption_scope {
+                headers.insert(DEFAULT_ENCRYPTION_SCOPE, default_encryption_scope);
+                if let Some(deny_encryption_scope_override) = self.deny_encryption_scope_override {
+                    headers.insert(
+                        DENY_ENCRYPTION_SCOPE_OVERRIDE,
+                        deny_encryption_scope_override.to_string(),
+                    );
+                }
+            }
+
             let mut request = ContainerClient::finalize_request(url, Method::Put, headers, None)?;
 
             let _response = self.client.send(&mut self.context, &mut request).await?;
@@ -42,3 +67,20 @@ impl CreateBuilder {
 }
 
 type CreateResponse = ();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_storage::StorageCredentials;
+
+    #[tokio::test]
+    async fn deny_override_requires_default_scope() {
+        let err = ClientBuilder::new("test", StorageCredentials::anonymous())
+            .container_client("container")
+            .create()
+            .deny_encryption_scope_override(true)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Other);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{prelude::*, service::operations::find_blobs_by_tags_request};
+use azure_core::prelude::*;
+
+pub use crate::service::operations::FindBlobsByTagsResponse;
+
+operation! {
+    #[stream]
+    FindBlobsByTags,
+    client: ContainerClient,
+    expression: String,
+    ?next_marker: NextMarker,
+    ?max_results: MaxResults
+}
+
+impl FindBlobsByTagsBuilder {
+    pub fn into_stream(self) -> FindBlobsByTags {
+        let make_request = move |next_marker: Option<NextMarker>| {
+            let this = self.clone();
+            let mut ctx = self.context.clone();
+            async move {
+                let mut url = this.client.url()?;
+                url.query_pairs_mut().append_pair("restype", "container");
+                let mut request = find_blobs_by_tags_request(
+                    url,
+                    &this.expression,
+                    next_marker,
+                    this.max_results,
+                )?;
+
+                let response = this.client.send(&mut ctx, &mut request).await?;
+
+                FindBlobsByTagsResponse::try_from(response).await
+            }
+        };
+        azure_core::Pageable::new(make_request)
+    }
+}
+
+pub type FindBlobsByTags = azure_core::Pageable<FindBlobsByTagsResponse, azure_core::error::Error>;
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, Method, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    GetMetadata,
+    client: ContainerClient,
+    ?lease_id: LeaseId
+}
+
+impl GetMetadataBuilder {
+    pub fn into_future(mut self) -> GetMetadata {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("restype", "container");
+            url.query_pairs_mut().append_pair("comp", "metadata");
+
+            let mut headers = Headers::new();
+            headers.add(self.lease_id);
+
+            let mut request = ContainerClient::finalize_request(url, Method::Get, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            response.headers().try_into()
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetMetadataResponse {
+    pub request_id: RequestId,
+    pub etag: String,
+    pub last_modified: OffsetDateTime,
+    pub date: OffsetDateTime,
+    pub metadata: Metadata,
+}
+
+impl TryFrom<&Headers> for GetMetadataResponse {
+    type Error = azure_core::Error;
+
+    fn try_from(headers: &Headers) -> Result<Self, Self::Error> {
+        Ok(GetMetadataResponse {
+            request_id: request_id_from_headers(headers)?,
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            metadata: headers.into(),
+        })
+    }
+}
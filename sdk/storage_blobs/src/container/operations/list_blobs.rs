@@ -5,6 +5,7 @@ use azure_core::{
     prelude::*,
     Method, Pageable, RequestId, Response as AzureResponse,
 };
+use futures::StreamExt;
 use time::OffsetDateTime;
 
 operation! {
@@ -144,6 +145,66 @@ pub struct BlobPrefix {
     pub name: String,
 }
 
+/// One level of a container's virtual directory structure, as returned by
+/// `ContainerClient::list_blob_tree`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobDirectory {
+    /// The prefix of this directory, including the trailing delimiter.
+    pub prefix: BlobPrefix,
+    /// The blobs directly inside this directory.
+    pub blobs: Vec<Blob>,
+    /// The subdirectories of this directory. These are only populated past
+    /// the first level when the listing was recursive.
+    pub directories: Vec<BlobDirectory>,
+}
+
+impl BlobDirectory {
+    fn new(name: String) -> Self {
+        Self {
+            prefix: BlobPrefix { name },
+            blobs: Vec::new(),
+            directories: Vec::new(),
+        }
+    }
+
+    /// List a single directory level using the `/` delimiter, optionally
+    /// descending into every prefix found.
+    pub(crate) fn list(
+        client: ContainerClient,
+        prefix: String,
+        recursive: bool,
+    ) -> futures::future::BoxFuture<'static, azure_core::Result<Self>> {
+        Box::pin(async move {
+            let mut directory = Self::new(prefix);
+            let mut builder = client.list_blobs().delimiter("/");
+            if !directory.prefix.name.is_empty() {
+                builder = builder.prefix(directory.prefix.name.clone());
+            }
+
+            let mut pages = builder.into_stream();
+            while let Some(page) = pages.next().await {
+                for item in page?.blobs.items {
+                    match item {
+                        BlobItem::Blob(blob) => directory.blobs.push(blob),
+                        BlobItem::BlobPrefix(prefix) => {
+                            directory.directories.push(Self::new(prefix.name))
+                        }
+                    }
+                }
+            }
+
+            if recursive {
+                for subdirectory in directory.directories.iter_mut() {
+                    let prefix = std::mem::take(&mut subdirectory.prefix.name);
+                    *subdirectory = Self::list(client.clone(), prefix, true).await?;
+                }
+            }
+
+            Ok(directory)
+        })
+    }
+}
+
 impl ListBlobsResponse {
     pub async fn try_from(response: AzureResponse) -> azure_core::Result<Self> {
         let (_, headers, body) = response.deconstruct();
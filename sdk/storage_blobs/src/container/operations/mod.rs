@@ -1,20 +1,28 @@
 pub mod acquire_lease;
 pub mod break_lease;
+pub mod change_lease;
 pub mod create;
 pub mod delete;
+pub mod find_blobs_by_tags;
 pub mod get_acl;
+pub mod get_metadata;
 pub mod get_properties;
 pub mod list_blobs;
 pub mod release_lease;
 pub mod renew_lease;
 pub mod set_acl;
+pub mod set_metadata;
 pub use self::acquire_lease::*;
 pub use self::break_lease::*;
+pub use self::change_lease::*;
 pub use self::create::*;
 pub use self::delete::*;
+pub use self::find_blobs_by_tags::*;
 pub use self::get_acl::*;
+pub use self::get_metadata::*;
 pub use self::get_properties::*;
 pub use self::list_blobs::*;
 pub use self::release_lease::*;
 pub use self::renew_lease::*;
 pub use self::set_acl::*;
+pub use self::set_metadata::*;
@@ -0,0 +1,92 @@
+use super::{AccessTier, DeleteSnapshotsMethod};
+
+/// The maximum number of sub-requests the service accepts in a single batch.
+pub const MAX_BATCH_SIZE: usize = 256;
+
+/// A set of blob operations that are sent to the service in a single
+/// `multipart/mixed` request.
+///
+/// See: <https://docs.microsoft.com/rest/api/storageservices/blob-batch>
+#[derive(Debug, Clone, Default)]
+pub struct BlobBatch {
+    operations: Vec<BlobBatchOperation>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum BlobBatchOperation {
+    Delete {
+        container_name: String,
+        blob_name: String,
+        delete_snapshots_method: Option<DeleteSnapshotsMethod>,
+    },
+    SetTier {
+        container_name: String,
+        blob_name: String,
+        access_tier: AccessTier,
+    },
+}
+
+impl BlobBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delete a blob.
+    #[must_use]
+    pub fn delete(
+        mut self,
+        container_name: impl Into<String>,
+        blob_name: impl Into<String>,
+    ) -> Self {
+        self.operations.push(BlobBatchOperation::Delete {
+            container_name: container_name.into(),
+            blob_name: blob_name.into(),
+            delete_snapshots_method: None,
+        });
+        self
+    }
+
+    /// Delete a blob along with its snapshots.
+    #[must_use]
+    pub fn delete_with_snapshots(
+        mut self,
+        container_name: impl Into<String>,
+        blob_name: impl Into<String>,
+        delete_snapshots_method: DeleteSnapshotsMethod,
+    ) -> Self {
+        self.operations.push(BlobBatchOperation::Delete {
+            container_name: container_name.into(),
+            blob_name: blob_name.into(),
+            delete_snapshots_method: Some(delete_snapshots_method),
+        });
+        self
+    }
+
+    /// Set the access tier of a blob.
+    #[must_use]
+    pub fn set_tier(
+        mut self,
+        container_name: impl Into<String>,
+        blob_name: impl Into<String>,
+        access_tier: AccessTier,
+    ) -> Self {
+        self.operations.push(BlobBatchOperation::SetTier {
+            container_name: container_name.into(),
+            blob_name: blob_name.into(),
+            access_tier,
+        });
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub(crate) fn operations(&self) -> &[BlobBatchOperation] {
+        &self.operations
+    }
+}
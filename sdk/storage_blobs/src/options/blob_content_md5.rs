@@ -28,7 +28,7 @@ impl From<ConsistencyMD5> for BlobContentMD5 {
 
 impl Header for BlobContentMD5 {
     fn name(&self) -> headers::HeaderName {
-        "x-ms-blob-content-md5".into()
+        super::BLOB_CONTENT_MD5
     }
 
     fn value(&self) -> headers::HeaderValue {
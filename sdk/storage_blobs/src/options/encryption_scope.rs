@@ -1,3 +1,4 @@
+use super::ENCRYPTION_SCOPE;
 use azure_core::headers::{self, Header};
 
 #[derive(Debug, Clone)]
@@ -5,7 +6,7 @@ pub struct EncryptionScope(String);
 
 impl Header for EncryptionScope {
     fn name(&self) -> headers::HeaderName {
-        "x-ms-encryption-scope".into()
+        ENCRYPTION_SCOPE
     }
 
     fn value(&self) -> headers::HeaderValue {
@@ -18,3 +19,9 @@ impl From<String> for EncryptionScope {
         Self(s)
     }
 }
+
+impl From<&str> for EncryptionScope {
+    fn from(s: &str) -> Self {
+        Self(s.to_owned())
+    }
+}
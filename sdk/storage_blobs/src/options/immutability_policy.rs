@@ -0,0 +1,49 @@
+use super::{IMMUTABILITY_POLICY_MODE, IMMUTABILITY_POLICY_UNTIL_DATE};
+use azure_core::{
+    date,
+    headers::{Header, HeaderName, HeaderValue, Headers},
+};
+use time::OffsetDateTime;
+
+create_enum!(
+    ImmutabilityPolicyMode,
+    (Unlocked, "Unlocked"),
+    (Locked, "Locked")
+);
+
+impl Header for ImmutabilityPolicyMode {
+    fn name(&self) -> HeaderName {
+        IMMUTABILITY_POLICY_MODE
+    }
+
+    fn value(&self) -> HeaderValue {
+        self.to_string().into()
+    }
+}
+
+/// A time-based retention policy that prevents a blob from being modified or
+/// deleted until `until`.
+///
+/// An unlocked policy can still be changed or removed. A locked policy can
+/// only be extended.
+#[derive(Debug, Clone)]
+pub struct ImmutabilityPolicy {
+    pub until: OffsetDateTime,
+    pub mode: ImmutabilityPolicyMode,
+}
+
+impl ImmutabilityPolicy {
+    pub fn new(until: OffsetDateTime, mode: ImmutabilityPolicyMode) -> Self {
+        Self { until, mode }
+    }
+
+    pub fn to_headers(&self) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert(
+            IMMUTABILITY_POLICY_UNTIL_DATE,
+            date::to_rfc1123(&self.until),
+        );
+        headers.add(self.mode);
+        headers
+    }
+}
@@ -2,6 +2,7 @@
 
 mod access_tier;
 mod ba512_range;
+mod blob_batch;
 mod blob_cache_control;
 mod blob_content_disposition;
 mod blob_content_encoding;
@@ -17,11 +18,15 @@ mod delete_snapshot_method;
 mod encryption_key;
 mod encryption_scope;
 mod hash;
+mod immutability_policy;
+mod query_format;
 mod rehydrate_policy;
 mod tags;
 
 pub use access_tier::AccessTier;
 pub use ba512_range::BA512Range;
+pub(crate) use blob_batch::BlobBatchOperation;
+pub use blob_batch::{BlobBatch, MAX_BATCH_SIZE};
 pub use blob_cache_control::BlobCacheControl;
 pub use blob_content_disposition::BlobContentDisposition;
 pub use blob_content_encoding::BlobContentEncoding;
@@ -37,6 +42,8 @@ pub use delete_snapshot_method::DeleteSnapshotsMethod;
 pub use encryption_key::CPKInfo;
 pub use encryption_scope::EncryptionScope;
 pub use hash::Hash;
+pub use immutability_policy::{ImmutabilityPolicy, ImmutabilityPolicyMode};
+pub use query_format::{DelimitedTextConfiguration, JsonTextConfiguration, QueryFormat};
 pub use rehydrate_policy::RehydratePriority;
 pub use tags::Tags;
 
@@ -49,6 +56,7 @@ request_query!(
     /// This type could also be a DateTime but the docs clearly states to treat is as opaque so we do not convert it in any way.
     ///
     ///See: <https://docs.microsoft.com/rest/api/storageservices/get-blob>"]
+    #[derive(PartialEq, Eq)]
     VersionId,
     "versionid"
 );
@@ -70,4 +78,32 @@ impl FromStr for Snapshot {
     }
 }
 
+impl FromStr for VersionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s.to_string()))
+    }
+}
+
 pub const SNAPSHOT: HeaderName = HeaderName::from_static("x-ms-snapshot");
+pub const VERSION_ID: HeaderName = HeaderName::from_static("x-ms-version-id");
+pub const IS_CURRENT_VERSION: HeaderName = HeaderName::from_static("x-ms-is-current-version");
+pub const BLOB_SEALED: HeaderName = HeaderName::from_static("x-ms-blob-sealed");
+pub const BLOB_APPEND_OFFSET: HeaderName = HeaderName::from_static("x-ms-blob-append-offset");
+pub const IMMUTABILITY_POLICY_UNTIL_DATE: HeaderName =
+    HeaderName::from_static("x-ms-immutability-policy-until-date");
+pub const IMMUTABILITY_POLICY_MODE: HeaderName =
+    HeaderName::from_static("x-ms-immutability-policy-mode");
+pub const LEGAL_HOLD: HeaderName = HeaderName::from_static("x-ms-legal-hold");
+pub const ENCRYPTION_SCOPE: HeaderName = HeaderName::from_static("x-ms-encryption-scope");
+pub const DEFAULT_ENCRYPTION_SCOPE: HeaderName =
+    HeaderName::from_static("x-ms-default-encryption-scope");
+pub const DENY_ENCRYPTION_SCOPE_OVERRIDE: HeaderName =
+    HeaderName::from_static("x-ms-deny-encryption-scope-override");
+pub const ACCESS_TIER_INFERRED: HeaderName = HeaderName::from_static("x-ms-access-tier-inferred");
+pub const ACCESS_TIER_CHANGE_TIME: HeaderName =
+    HeaderName::from_static("x-ms-access-tier-change-time");
+pub const BLOB_CONTENT_MD5: HeaderName = HeaderName::from_static("x-ms-blob-content-md5");
+pub const ARCHIVE_STATUS: HeaderName = HeaderName::from_static("x-ms-archive-status");
+pub const REHYDRATE_PRIORITY: HeaderName = HeaderName::from_static("x-ms-rehydrate-priority");
@@ -0,0 +1,59 @@
+/// The serialization of the blob contents, or of the result, of a blob query.
+///
+/// See: <https://docs.microsoft.com/rest/api/storageservices/query-blob-contents>
+#[derive(Debug, Clone)]
+pub enum QueryFormat {
+    Delimited(DelimitedTextConfiguration),
+    Json(JsonTextConfiguration),
+}
+
+impl QueryFormat {
+    /// Comma separated values with a header row.
+    pub fn csv() -> Self {
+        Self::Delimited(DelimitedTextConfiguration {
+            has_headers: true,
+            ..Default::default()
+        })
+    }
+
+    /// Newline delimited JSON.
+    pub fn json() -> Self {
+        Self::Json(JsonTextConfiguration::default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelimitedTextConfiguration {
+    pub column_separator: String,
+    pub field_quote: String,
+    pub record_separator: String,
+    pub escape_char: String,
+    pub has_headers: bool,
+}
+
+impl Default for DelimitedTextConfiguration {
+    fn default() -> Self {
+        Self {
+            column_separator: ",".to_owned(),
+            field_quote: "\"".to_owned(),
+            record_separator: "\n".to_owned(),
+            escape_char: String::new(),
+            has_headers: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct JsonTextConfiguration {
+    pub record_separator: String,
+}
+
+impl Default for JsonTextConfiguration {
+    fn default() -> Self {
+        Self {
+            record_separator: "\n".to_owned(),
+        }
+    }
+}
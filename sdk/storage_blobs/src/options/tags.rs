@@ -1,6 +1,6 @@
 use azure_core::{
-    error::Error,
-    headers::{Header, HeaderName, HeaderValue, TAGS},
+    error::{Error, ErrorKind},
+    headers::{Header, HeaderName, HeaderValue, Headers, TAGS},
     xml::to_xml,
 };
 use bytes::{Bytes, BytesMut};
@@ -17,6 +17,7 @@ use url::form_urlencoded;
 /// * Tag keys must be between one and 128 characters
 /// * Tag values must be between zero and 256 characters
 /// * Tag keys and values are case-sensitive
+/// * Tag keys must be unique
 /// * Tag keys and values only support string data types. Any numbers, dates, times, or special characters are saved as strings
 /// * Tag keys and values must adhere to the following naming rules:
 ///      * Alphanumeric characters:
@@ -59,6 +60,69 @@ impl Tags {
         });
     }
 
+    /// Check the tags against the blob index tag limits documented on
+    /// [`Tags`].
+    pub fn validate(&self) -> azure_core::Result<()> {
+        if self.tag_set.tags.len() > MAX_TAGS {
+            return Err(Error::with_message(ErrorKind::DataConversion, || {
+                format!(
+                    "a blob can have at most {MAX_TAGS} tags, got {}",
+                    self.tag_set.tags.len()
+                )
+            }));
+        }
+
+        for (i, tag) in self.tag_set.tags.iter().enumerate() {
+            if self.tag_set.tags[..i]
+                .iter()
+                .any(|other| other.key == tag.key)
+            {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!("duplicate tag key: {:?}", tag.key)
+                }));
+            }
+            let key_len = tag.key.chars().count();
+            if !(1..=MAX_TAG_KEY_LEN).contains(&key_len) {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "tag key must be between 1 and {MAX_TAG_KEY_LEN} characters: {:?}",
+                        tag.key
+                    )
+                }));
+            }
+            if tag.value.chars().count() > MAX_TAG_VALUE_LEN {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "tag value must be at most {MAX_TAG_VALUE_LEN} characters: {:?}",
+                        tag.value
+                    )
+                }));
+            }
+            if !tag.key.chars().all(is_valid_tag_char) {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!("tag key contains invalid characters: {:?}", tag.key)
+                }));
+            }
+            if !tag.value.chars().all(is_valid_tag_char) {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!("tag value contains invalid characters: {:?}", tag.value)
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate `tags` and add them to `headers` as `x-ms-tags`, as done by
+    /// the operations that set tags while creating a blob.
+    pub(crate) fn add_header(headers: &mut Headers, tags: Option<Tags>) -> azure_core::Result<()> {
+        if let Some(tags) = tags {
+            tags.validate()?;
+            headers.add(tags);
+        }
+        Ok(())
+    }
+
     pub fn to_xml(&self) -> azure_core::Result<Bytes> {
         let mut value = BytesMut::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>");
         value.extend(to_xml(&self)?);
@@ -66,6 +130,14 @@ impl Tags {
     }
 }
 
+const MAX_TAGS: usize = 10;
+const MAX_TAG_KEY_LEN: usize = 128;
+const MAX_TAG_VALUE_LEN: usize = 256;
+
+fn is_valid_tag_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, ' ' | '+' | '-' | '.' | ':' | '=' | '_' | '/')
+}
+
 impl<K, V> Extend<(K, V)> for Tags
 where
     K: Into<String>,
@@ -170,4 +242,59 @@ mod tests {
 
         Ok(())
     }
+    #[test]
+    fn validate_tags() {
+        let mut tags = Tags::new();
+        tags.insert("project", "x");
+        tags.insert("path", "a/b c+d-e.f:g=h_i");
+        tags.insert("empty", "");
+        assert!(tags.validate().is_ok());
+
+        let mut tags = Tags::new();
+        tags.insert("", "value");
+        assert!(tags.validate().is_err());
+
+        let mut tags = Tags::new();
+        tags.insert("k".repeat(129), "value");
+        assert!(tags.validate().is_err());
+
+        let mut tags = Tags::new();
+        tags.insert("key", "v".repeat(257));
+        assert!(tags.validate().is_err());
+
+        let mut tags = Tags::new();
+        tags.insert("key?", "value");
+        assert!(tags.validate().is_err());
+
+        let mut tags = Tags::new();
+        tags.insert("key", "value&");
+        assert!(tags.validate().is_err());
+
+        let mut tags = Tags::new();
+        tags.insert("key", "a");
+        tags.insert("key", "b");
+        assert!(tags.validate().is_err());
+
+        let mut tags = Tags::new();
+        tags.extend((0..11).map(|i| (format!("key{i}"), "value")));
+        assert!(tags.validate().is_err());
+    }
+
+    #[test]
+    fn add_header_validates() -> azure_core::Result<()> {
+        let mut headers = Headers::new();
+        Tags::add_header(&mut headers, None)?;
+        assert!(headers.get_optional_str(&TAGS).is_none());
+
+        let mut tags = Tags::new();
+        tags.insert("key?", "value");
+        assert!(Tags::add_header(&mut headers, Some(tags)).is_err());
+        assert!(headers.get_optional_str(&TAGS).is_none());
+
+        let mut tags = Tags::new();
+        tags.insert("project", "a b");
+        Tags::add_header(&mut headers, Some(tags))?;
+        assert_eq!(headers.get_optional_str(&TAGS), Some("project=a+b"));
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
-use crate::prelude::BlobServiceClient;
-use azure_core::{prelude::*, Response as HttpResponse};
+use crate::prelude::{BlobServiceClient, Tags};
+use azure_core::{headers::Headers, prelude::*, Method, Request, Response as HttpResponse, Url};
 use azure_storage::headers::CommonStorageResponseHeaders;
 
 operation! {
@@ -17,18 +17,11 @@ impl FindBlobsByTagsBuilder {
             let this = self.clone();
             let mut ctx = self.context.clone();
             async move {
-                let mut url = this.client.url()?;
-
-                url.query_pairs_mut().append_pair("comp", "blobs");
-                if let Some(next_marker) = next_marker {
-                    next_marker.append_to_url_query(&mut url);
-                }
-                url.query_pairs_mut().append_pair("where", &this.expression);
-                let mut request = BlobServiceClient::finalize_request(
-                    url,
-                    azure_core::Method::Get,
-                    azure_core::headers::Headers::new(),
-                    None,
+                let mut request = find_blobs_by_tags_request(
+                    this.client.url()?,
+                    &this.expression,
+                    next_marker,
+                    this.max_results,
                 )?;
 
                 let response = this.client.send(&mut ctx, &mut request).await?;
@@ -42,6 +35,25 @@ impl FindBlobsByTagsBuilder {
 
 pub type FindBlobsByTags = azure_core::Pageable<FindBlobsByTagsResponse, azure_core::error::Error>;
 
+/// Build the request for one page of blobs matching `expression`, below the
+/// account or container `url`.
+pub(crate) fn find_blobs_by_tags_request(
+    mut url: Url,
+    expression: &str,
+    next_marker: Option<NextMarker>,
+    max_results: Option<MaxResults>,
+) -> azure_core::Result<Request> {
+    url.query_pairs_mut().append_pair("comp", "blobs");
+    if let Some(next_marker) = next_marker {
+        next_marker.append_to_url_query(&mut url);
+    }
+    if let Some(max_results) = max_results {
+        max_results.append_to_url_query(&mut url);
+    }
+    url.query_pairs_mut().append_pair("where", expression);
+    BlobServiceClient::finalize_request(url, Method::Get, Headers::new(), None)
+}
+
 #[derive(Debug, Clone)]
 pub struct FindBlobsByTagsResponse {
     pub blobs: Vec<Blob>,
@@ -59,7 +71,7 @@ impl Continuable for FindBlobsByTagsResponse {
 }
 
 impl FindBlobsByTagsResponse {
-    async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+    pub(crate) async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
         let (_status_code, headers, body) = response.deconstruct();
         let body: ListBlobsByTagsBody = body.xml().await?;
 
@@ -93,7 +105,13 @@ struct Blobs {
 pub struct Blob {
     pub name: String,
     pub container_name: String,
+    /// The value of the tag matched by the query, as returned by service
+    /// versions prior to 2019-12-12. Empty when the service returns `tags`
+    /// instead.
+    #[serde(default)]
     pub tag_value: String,
+    /// The tags matched by the query.
+    pub tags: Option<Tags>,
 }
 
 #[cfg(test)]
@@ -119,6 +137,38 @@ mod tests {
         let body: ListBlobsByTagsBody = read_xml(BODY)?;
         assert_eq!(body.blobs.blobs.len(), 1);
         assert_eq!(body.blobs.blobs[0].name, "test1");
+        assert_eq!(body.blobs.blobs[0].tag_value, "value1");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_body_with_tag_set() -> azure_core::Result<()> {
+        const BODY: &[u8] = b"<?xml version=\"1.0\" encoding=\"utf-8\"?>
+        <EnumerationResults ServiceEndpoint=\"https://myaccount.blob.core.windows.net/\">
+          <Where>\"project\"='x' AND \"env\"='prod'</Where>
+          <Blobs>
+            <Blob>
+              <Name>test1</Name>
+              <ContainerName>container1</ContainerName>
+              <Tags>
+                <TagSet>
+                  <Tag><Key>project</Key><Value>x</Value></Tag>
+                  <Tag><Key>env</Key><Value>prod</Value></Tag>
+                </TagSet>
+              </Tags>
+            </Blob>
+          </Blobs>
+          <NextMarker>marker</NextMarker>
+        </EnumerationResults>";
+
+        let body: ListBlobsByTagsBody = read_xml(BODY)?;
+        assert_eq!(body.next_marker.as_deref(), Some("marker"));
+        assert_eq!(body.blobs.blobs.len(), 1);
+        assert_eq!(body.blobs.blobs[0].tag_value, "");
+        let tags = body.blobs.blobs[0].tags.clone().unwrap();
+        assert_eq!(tags.tag_set.tags.len(), 2);
+        assert_eq!(tags.tag_set.tags[1].key, "env");
+        assert_eq!(tags.tag_set.tags[1].value, "prod");
         Ok(())
     }
 }
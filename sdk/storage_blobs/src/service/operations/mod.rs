@@ -3,9 +3,11 @@ mod get_account_information;
 mod get_blob_service_properties;
 mod get_user_delegation_key;
 mod list_containers;
+mod submit_batch;
 
 pub use find_blobs_by_tags::*;
 pub use get_account_information::*;
 pub use get_blob_service_properties::*;
 pub use get_user_delegation_key::*;
 pub use list_containers::*;
+pub use submit_batch::*;
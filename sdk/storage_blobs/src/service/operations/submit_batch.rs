@@ -0,0 +1,324 @@
+use crate::prelude::*;
+use azure_core::{
+    date,
+    error::{Error, ErrorKind},
+    headers::*,
+    Method, Request, StatusCode,
+};
+use azure_storage::{
+    authorize_request, clients::ServiceType, headers::CommonStorageResponseHeaders,
+};
+use bytes::Bytes;
+use std::fmt::Write;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+operation! {
+    SubmitBatch,
+    client: BlobServiceClient,
+    batch: BlobBatch,
+}
+
+impl SubmitBatchBuilder {
+    pub fn into_future(mut self) -> SubmitBatch {
+        Box::pin(async move {
+            if self.batch.is_empty() || self.batch.len() > MAX_BATCH_SIZE {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "a batch must contain between 1 and {MAX_BATCH_SIZE} operations, got {}",
+                        self.batch.len()
+                    )
+                }));
+            }
+
+            let mut sub_requests = Vec::with_capacity(self.batch.len());
+            for operation in self.batch.operations() {
+                let mut sub_request = self.sub_request(operation)?;
+                authorize_request(
+                    self.client.credentials(),
+                    self.client.token_scope(),
+                    ServiceType::Blob,
+                    &mut sub_request,
+                )
+                .await?;
+                sub_requests.push(sub_request);
+            }
+
+            let boundary = format!("batch_{}", Uuid::new_v4());
+            let body = encode_batch(&boundary, &sub_requests);
+
+            let mut url = self.client.url()?;
+            url.query_pairs_mut().append_pair("comp", "batch");
+
+            let mut headers = Headers::new();
+            headers.insert(
+                CONTENT_TYPE,
+                format!("multipart/mixed; boundary={boundary}"),
+            );
+
+            let mut request =
+                BlobServiceClient::finalize_request(url, Method::Post, headers, Some(body.into()))?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            let (_, headers, body) = response.deconstruct();
+            let body = body.collect_string().await?;
+            SubmitBatchResponse::try_from(&headers, &body)
+        })
+    }
+
+    fn sub_request(&self, operation: &BlobBatchOperation) -> azure_core::Result<Request> {
+        let mut headers = Headers::new();
+        headers.insert(MS_DATE, date::to_rfc1123(&OffsetDateTime::now_utc()));
+        headers.insert(CONTENT_LENGTH, "0");
+
+        let (url, method) = match operation {
+            BlobBatchOperation::Delete {
+                container_name,
+                blob_name,
+                delete_snapshots_method,
+            } => {
+                headers.add(*delete_snapshots_method);
+                let url = self
+                    .client
+                    .try_container_client(container_name)?
+                    .try_blob_client(blob_name)?
+                    .url()?;
+                (url, Method::Delete)
+            }
+            BlobBatchOperation::SetTier {
+                container_name,
+                blob_name,
+                access_tier,
+            } => {
+                headers.add(*access_tier);
+                let mut url = self
+                    .client
+                    .try_container_client(container_name)?
+                    .try_blob_client(blob_name)?
+                    .url()?;
+                url.query_pairs_mut().append_pair("comp", "tier");
+                (url, Method::Put)
+            }
+        };
+
+        let mut request = Request::new(url, method);
+        for (name, value) in headers {
+            request.insert_header(name, value);
+        }
+        Ok(request)
+    }
+}
+
+// Each sub-request is sent as an `application/http` part. The service only
+// expects the path and query in the request line, not the full URL.
+fn encode_batch(boundary: &str, sub_requests: &[Request]) -> Bytes {
+    let mut body = String::new();
+    for (content_id, sub_request) in sub_requests.iter().enumerate() {
+        let url = sub_request.url();
+        let path = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_owned(),
+        };
+
+        write!(
+            body,
+            "--{boundary}\r\n\
+             Content-Type: application/http\r\n\
+             Content-Transfer-Encoding: binary\r\n\
+             Content-ID: {content_id}\r\n\
+             \r\n\
+             {} {path} HTTP/1.1\r\n",
+            sub_request.method().as_ref()
+        )
+        .expect("writing to a String cannot fail");
+        for (name, value) in sub_request.headers().iter() {
+            write!(body, "{}: {}\r\n", name.as_str(), value.as_str())
+                .expect("writing to a String cannot fail");
+        }
+        body.push_str("\r\n");
+    }
+    write!(body, "--{boundary}--\r\n").expect("writing to a String cannot fail");
+    Bytes::from(body)
+}
+
+/// The outcome of one operation of a batch.
+#[derive(Debug, Clone)]
+pub struct BlobBatchOperationResponse {
+    pub status: StatusCode,
+    pub error_code: Option<String>,
+}
+
+impl BlobBatchOperationResponse {
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitBatchResponse {
+    pub common: CommonStorageResponseHeaders,
+    /// The responses of the individual operations, in the order they were added to the batch.
+    pub responses: Vec<BlobBatchOperationResponse>,
+}
+
+impl SubmitBatchResponse {
+    pub(crate) fn try_from(headers: &Headers, body: &str) -> azure_core::Result<Self> {
+        let common = CommonStorageResponseHeaders::try_from(headers)?;
+        let content_type = headers.get_str(&CONTENT_TYPE)?;
+        let responses = decode_batch(content_type, body)?;
+        Ok(Self { common, responses })
+    }
+}
+
+fn decode_batch(
+    content_type: &str,
+    body: &str,
+) -> azure_core::Result<Vec<BlobBatchOperationResponse>> {
+    let boundary = content_type
+        .split(';')
+        .find_map(|param| param.trim().strip_prefix("boundary="))
+        .ok_or_else(|| {
+            Error::with_message(ErrorKind::DataConversion, || {
+                format!("batch response content type has no boundary: {content_type}")
+            })
+        })?;
+    let delimiter = format!("--{boundary}");
+
+    let mut responses = Vec::new();
+    for part in body.split(delimiter.as_str()).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+        responses.push(decode_part(part)?);
+    }
+
+    responses.sort_by_key(|(content_id, _)| *content_id);
+    Ok(responses
+        .into_iter()
+        .map(|(_, response)| response)
+        .collect())
+}
+
+fn decode_part(part: &str) -> azure_core::Result<(Option<u64>, BlobBatchOperationResponse)> {
+    let invalid = || {
+        Error::with_message(ErrorKind::DataConversion, || {
+            format!("invalid batch response part: {part}")
+        })
+    };
+
+    let mut lines = part
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty());
+
+    let mut content_id = None;
+    for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-id") {
+                content_id = value.trim().parse().ok();
+            }
+        }
+    }
+
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .and_then(|code| StatusCode::try_from(code).ok())
+        .ok_or_else(invalid)?;
+
+    let mut error_code = None;
+    for line in lines.take_while(|line| !line.is_empty()) {
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case(ERROR_CODE.as_str()) {
+                error_code = Some(value.trim().to_owned());
+            }
+        }
+    }
+
+    Ok((
+        content_id,
+        BlobBatchOperationResponse { status, error_code },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::Url;
+    use azure_storage::StorageCredentials;
+
+    #[test]
+    fn encode_request() {
+        let mut sub_request = Request::new(
+            Url::parse("https://a.blob.core.windows.net/c/b?comp=tier").unwrap(),
+            Method::Put,
+        );
+        sub_request.insert_header(BLOB_ACCESS_TIER, "Cool");
+
+        let body = encode_batch("batch_1", &[sub_request]);
+        assert_eq!(
+            body,
+            "--batch_1\r\n\
+             Content-Type: application/http\r\n\
+             Content-Transfer-Encoding: binary\r\n\
+             Content-ID: 0\r\n\
+             \r\n\
+             PUT /c/b?comp=tier HTTP/1.1\r\n\
+             x-ms-access-tier: Cool\r\n\
+             \r\n\
+             --batch_1--\r\n"
+        );
+    }
+
+    #[test]
+    fn sub_request_validates_names() {
+        let builder = ClientBuilder::new("a", StorageCredentials::anonymous())
+            .blob_service_client()
+            .submit_batch(BlobBatch::new());
+        assert!(builder
+            .sub_request(&BlobBatchOperation::Delete {
+                container_name: "My_Container".to_owned(),
+                blob_name: "b".to_owned(),
+                delete_snapshots_method: None,
+            })
+            .is_err());
+        assert!(builder
+            .sub_request(&BlobBatchOperation::Delete {
+                container_name: "ccc".to_owned(),
+                blob_name: String::new(),
+                delete_snapshots_method: None,
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn decode_response() -> azure_core::Result<()> {
+        let body = "--batchresponse_1\r\n\
+                    Content-Type: application/http\r\n\
+                    Content-ID: 1\r\n\
+                    \r\n\
+                    HTTP/1.1 404 The specified blob does not exist.\r\n\
+                    x-ms-error-code: BlobNotFound\r\n\
+                    x-ms-request-id: 778fdc83-801e-0000-62ff-0334671e2852\r\n\
+                    \r\n\
+                    --batchresponse_1\r\n\
+                    Content-Type: application/http\r\n\
+                    Content-ID: 0\r\n\
+                    \r\n\
+                    HTTP/1.1 202 Accepted\r\n\
+                    x-ms-delete-type-permanent: true\r\n\
+                    \r\n\
+                    --batchresponse_1--\r\n";
+
+        let responses = decode_batch("multipart/mixed; boundary=batchresponse_1", body)?;
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].status, StatusCode::Accepted);
+        assert!(responses[0].is_success());
+        assert_eq!(responses[0].error_code, None);
+        assert_eq!(responses[1].status, StatusCode::NotFound);
+        assert_eq!(responses[1].error_code.as_deref(), Some("BlobNotFound"));
+        Ok(())
+    }
+}
@@ -61,3 +61,37 @@ async fn put_append_blob() {
     assert_eq!(resp.metadata.get("second"), Some(Bytes::from("something")));
     assert_eq!(resp.metadata.get("not_found"), None);
 }
+
+#[tokio::test]
+async fn append_block_stream() {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let access_key =
+        std::env::var("STORAGE_ACCESS_KEY").expect("Set env variable STORAGE_ACCESS_KEY first!");
+
+    let container_name = format!("append-stream-{}", uuid::Uuid::new_v4());
+
+    let storage_credentials = StorageCredentials::access_key(account.clone(), access_key);
+    let blob_service = BlobServiceClient::new(account, storage_credentials);
+    let container = blob_service.container_client(&container_name);
+    let blob = container.blob_client("log.txt");
+
+    container.create().await.unwrap();
+    blob.put_append_blob().await.unwrap();
+
+    let data: Vec<u8> = (0..5 * 1024 * 1024u32).map(|i| (i % 251) as u8).collect();
+    let appended = blob
+        .append_block_stream(futures::io::Cursor::new(data.clone()))
+        .await
+        .unwrap();
+    assert_eq!(appended, data.len() as u64);
+
+    let appended = blob.append_block_stream(&b"tail"[..]).await.unwrap();
+    assert_eq!(appended, 4);
+
+    let content = blob.get_content().await.unwrap();
+    assert_eq!(&content[..data.len()], &data[..]);
+    assert_eq!(&content[data.len()..], b"tail");
+
+    container.delete().await.unwrap();
+}
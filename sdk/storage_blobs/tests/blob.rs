@@ -2,7 +2,7 @@
 #[macro_use]
 extern crate log;
 
-use azure_core::{date, Url};
+use azure_core::{date, prelude::IfMatchCondition, Url};
 use azure_storage::prelude::*;
 use azure_storage_blobs::container::operations::ListBlobsResponse;
 use azure_storage_blobs::{blob::BlockListType, container::PublicAccess, prelude::*};
@@ -317,6 +317,269 @@ async fn put_block_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn put_block_blob_parallel() -> azure_core::Result<()> {
+    let container = create_test_container("parallel").await?;
+    let blob = container.blob_client("parallel.bin");
+
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+    blob.put_block_blob_parallel(data.clone(), 1024, 4).await?;
+
+    let block_list = blob.get_block_list().await?.block_with_size_list;
+    assert_eq!(block_list.blocks.len(), 10);
+    assert_eq!(blob.get_content().await?, data);
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_block_blob_parallel() -> azure_core::Result<()> {
+    let container = create_test_container("resume").await?;
+    let blob = container.blob_client("resume.bin");
+
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+    // simulate an interrupted upload that staged only the first block
+    blob.put_block(format!("{:032x}", 0), data[..1024].to_vec())
+        .await?;
+
+    blob.resume_block_blob_parallel(data.clone(), 1024, 4)
+        .await?;
+
+    assert_eq!(blob.get_content().await?, data);
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_and_get_empty_block_blob() -> azure_core::Result<()> {
+    let container = create_test_container("empty").await?;
+    let blob = container.blob_client("empty.bin");
+
+    blob.put_block_blob(Bytes::new()).await?;
+
+    let properties = blob.get_properties().await?;
+    assert_eq!(properties.blob.properties.content_length, 0);
+    assert!(blob.get_content().await?.is_empty());
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_block_blob_with_tags() -> azure_core::Result<()> {
+    let container = create_test_container("tags").await?;
+    let blob = container.blob_client("tagged.txt");
+
+    let mut tags = Tags::new();
+    tags.insert("project", "backlog");
+    blob.put_block_blob("tagged").tags(tags.clone()).await?;
+
+    let response = blob.get_tags().await?;
+    assert_eq!(response.tags, tags);
+
+    let mut invalid = Tags::new();
+    invalid.insert("key?", "value");
+    assert!(blob.put_block_blob("tagged").tags(invalid).await.is_err());
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_if_tags() -> azure_core::Result<()> {
+    let container = create_test_container("iftags").await?;
+    let blob = container.blob_client("tagged.txt");
+
+    let mut tags = Tags::new();
+    tags.insert("status", "live");
+    blob.put_block_blob("tagged").tags(tags).await?;
+
+    let err = blob
+        .delete()
+        .if_tags("\"status\" = 'stale'")
+        .await
+        .expect_err("tag condition should not be met");
+    assert!(azure_storage_blobs::blob::is_condition_not_met(&err));
+
+    blob.delete().if_tags("\"status\" = 'live'").await?;
+    assert!(!blob.exists().await?);
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn wait_for_copy() -> azure_core::Result<()> {
+    let container = create_test_container("copy").await?;
+    let source = container.blob_client("source.txt");
+    let destination = container.blob_client("destination.txt");
+
+    source.put_block_blob("copy me").await?;
+
+    destination.copy(source.url()?).await?;
+    let blob = destination
+        .wait_for_copy(Duration::from_millis(500), Some(Duration::from_secs(60)))
+        .await?;
+    assert_eq!(
+        blob.properties.copy_status,
+        Some(azure_storage_blobs::blob::CopyStatus::Success)
+    );
+    assert_eq!(destination.get_content().await?, b"copy me");
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_properties_if_none_match() -> azure_core::Result<()> {
+    let container = create_test_container("etag").await?;
+    let blob = container.blob_client("cached.txt");
+    let etag = blob.put_block_blob("cached").await?.etag;
+
+    let err = blob
+        .get_properties()
+        .if_match(IfMatchCondition::NotMatch(etag.clone()))
+        .await
+        .expect_err("blob should not have changed");
+    assert!(azure_storage_blobs::blob::is_not_modified(&err));
+
+    blob.put_block_blob("changed").await?;
+    blob.get_properties()
+        .if_match(IfMatchCondition::NotMatch(etag))
+        .await?;
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_status_helpers() -> azure_core::Result<()> {
+    let container = create_test_container("status").await?;
+    let blob = container.blob_client("missing.txt");
+
+    let err = blob
+        .get_properties()
+        .await
+        .expect_err("blob should not exist");
+    assert!(azure_storage::error::is_not_found(&err));
+    assert!(!azure_storage::error::is_conflict(&err));
+
+    let err = container
+        .create()
+        .await
+        .expect_err("container already exists");
+    assert!(azure_storage::error::is_conflict(&err));
+    assert!(!azure_storage::error::is_throttled(&err));
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn append_to_block_blob_is_invalid_blob_type() -> azure_core::Result<()> {
+    let container = create_test_container("blobtype").await?;
+    let blob = container.blob_client("block.txt");
+    blob.put_block_blob("block").await?;
+
+    let err = blob
+        .append_block("append")
+        .await
+        .expect_err("a block blob cannot be appended to");
+    assert!(azure_storage_blobs::blob::is_invalid_blob_type(&err));
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn submit_batch() -> azure_core::Result<()> {
+    let container = create_test_container("batch").await?;
+
+    container.blob_client("a").put_block_blob("a").await?;
+    container.blob_client("b").put_block_blob("b").await?;
+
+    let batch = BlobBatch::new()
+        .set_tier(container.container_name(), "a", AccessTier::Cool)
+        .delete(container.container_name(), "b")
+        .delete(container.container_name(), "missing");
+    let response = container.service_client().submit_batch(batch).await?;
+
+    assert_eq!(response.responses.len(), 3);
+    assert!(response.responses[0].is_success());
+    assert!(response.responses[1].is_success());
+    assert_eq!(
+        response.responses[2].error_code.as_deref(),
+        Some("BlobNotFound")
+    );
+    assert!(!container.blob_client("b").exists().await?);
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_blob() -> azure_core::Result<()> {
+    let container = create_test_container("rename").await?;
+    let blob = container.blob_client("before");
+
+    blob.put_block_blob("data").await?;
+    let renamed = blob.rename("after", Duration::from_secs(60)).await?;
+
+    assert_eq!(renamed.blob_name(), "after");
+    assert!(!blob.exists().await?);
+    assert_eq!(renamed.get_content().await?, b"data");
+
+    renamed.snapshot().await?;
+    assert!(renamed
+        .rename("again", Duration::from_secs(60))
+        .await
+        .is_err());
+    assert!(renamed.exists().await?);
+    assert!(!container.blob_client("again").exists().await?);
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_blob() -> azure_core::Result<()> {
+    let container = create_test_container("query").await?;
+    let blob = container.blob_client("data.csv");
+
+    blob.put_block_blob("name,count\na,1\nb,2\nc,3\n").await?;
+
+    let response = blob
+        .query("SELECT name FROM BlobStorage WHERE name <> 'a'")
+        .input_format(QueryFormat::csv())
+        .output_format(QueryFormat::Delimited(Default::default()))
+        .await?;
+    assert_eq!(&response.data[..], b"b\nc\n");
+    assert!(response.errors.is_empty());
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_block_blob_without_overwrite() -> azure_core::Result<()> {
+    let container = create_test_container("overwrite").await?;
+    let blob = container.blob_client("blob");
+
+    blob.put_block_blob("first").overwrite(false).await?;
+    let err = blob
+        .put_block_blob("second")
+        .overwrite(false)
+        .await
+        .expect_err("second upload should not overwrite the blob");
+    assert!(azure_storage_blobs::blob::is_blob_already_exists(&err));
+    assert_eq!(blob.get_content().await?, b"first");
+
+    container.delete().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn copy_blob() -> azure_core::Result<()> {
     let blob_name: &'static str = "copysrc";
@@ -561,6 +824,13 @@ fn send_check() {
     );
 }
 
+// Create a container with a unique name starting with `prefix`.
+async fn create_test_container(prefix: &str) -> azure_core::Result<ContainerClient> {
+    let container = initialize().container_client(format!("{prefix}-{}", Uuid::new_v4()));
+    container.create().await?;
+    Ok(container)
+}
+
 fn initialize() -> BlobServiceClient {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
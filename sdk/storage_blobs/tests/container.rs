@@ -1,4 +1,5 @@
 #![cfg(all(test, feature = "test_e2e"))]
+use azure_core::prelude::*;
 use azure_storage::prelude::*;
 use azure_storage_blobs::{container::PublicAccess, prelude::*};
 use std::time::Duration;
@@ -60,6 +61,60 @@ async fn break_lease() {
     container.delete().await.unwrap();
 }
 
+#[tokio::test]
+async fn set_and_get_metadata() {
+    let container_name: &'static str = "azuresdkrustetoets4";
+
+    let storage = initialize();
+    let container = storage.container_client(container_name);
+
+    container
+        .create()
+        .public_access(PublicAccess::None)
+        .await
+        .unwrap();
+
+    let mut metadata = Metadata::new();
+    metadata.insert("project", "rust");
+    container.set_metadata().metadata(metadata).await.unwrap();
+
+    let res = container.get_metadata().await.unwrap();
+    assert_eq!(res.metadata.get("project").unwrap(), "rust");
+
+    container.delete().await.unwrap();
+}
+
+#[tokio::test]
+async fn list_blob_tree() -> azure_core::Result<()> {
+    let container_name = format!("tree-{}", uuid::Uuid::new_v4());
+    let storage = initialize();
+    let container = storage.container_client(&container_name);
+    container.create().await?;
+
+    for name in ["root.txt", "a/one.txt", "a/b/two.txt", "c/three.txt"] {
+        container.blob_client(name).put_block_blob("data").await?;
+    }
+
+    let root = container.list_blob_tree("", true).await?;
+    assert_eq!(root.prefix.name, "");
+    assert_eq!(root.blobs.len(), 1);
+    assert_eq!(root.blobs[0].name, "root.txt");
+    assert_eq!(root.directories.len(), 2);
+    let a = &root.directories[0];
+    assert_eq!(a.prefix.name, "a/");
+    assert_eq!(a.blobs[0].name, "a/one.txt");
+    assert_eq!(a.directories[0].prefix.name, "a/b/");
+    assert_eq!(a.directories[0].blobs[0].name, "a/b/two.txt");
+
+    let shallow = container.list_blob_tree("a/", false).await?;
+    assert_eq!(shallow.blobs.len(), 1);
+    assert_eq!(shallow.directories.len(), 1);
+    assert!(shallow.directories[0].blobs.is_empty());
+
+    container.delete().await?;
+    Ok(())
+}
+
 fn initialize() -> BlobServiceClient {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
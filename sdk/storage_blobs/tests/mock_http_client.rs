@@ -0,0 +1,478 @@
+use azure_core::{
+    error::ErrorKind,
+    headers::{self, Headers},
+    Context, HttpClient, Method, Request, StatusCode, TransportOptions, Url,
+};
+use azure_storage::StorageCredentials;
+use azure_storage_blobs::prelude::*;
+use futures::StreamExt;
+use mock_transport::MockHttpClient;
+use std::sync::Arc;
+
+fn client(http_client: MockHttpClient) -> BlobServiceClient {
+    shared_client(Arc::new(http_client))
+}
+
+fn shared_client(http_client: Arc<MockHttpClient>) -> BlobServiceClient {
+    ClientBuilder::new("account", StorageCredentials::anonymous())
+        .transport(TransportOptions::new(http_client))
+        .blob_service_client()
+}
+
+fn request(method: Method, path_and_query: &str) -> Request {
+    let url = Url::parse("https://account.blob.core.windows.net")
+        .unwrap()
+        .join(path_and_query)
+        .unwrap();
+    Request::new(url, method)
+}
+
+#[tokio::test]
+async fn matches_method_and_path() -> azure_core::Result<()> {
+    let http_client = MockHttpClient::new()
+        .with_response(Method::Get, "/a", StatusCode::Ok, Headers::new(), "get")
+        .with_response(Method::Head, "/a", StatusCode::NotFound, Headers::new(), "");
+
+    let response = http_client
+        .execute_request(&request(Method::Get, "/a?comp=tags"))
+        .await?;
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert_eq!(&response.into_body().collect().await?[..], b"get");
+
+    let response = http_client
+        .execute_request(&request(Method::Head, "/a"))
+        .await?;
+    assert_eq!(response.status(), StatusCode::NotFound);
+    Ok(())
+}
+
+#[tokio::test]
+async fn matches_query_when_registered() -> azure_core::Result<()> {
+    let http_client = MockHttpClient::new().with_response(
+        Method::Get,
+        "/a?comp=tags",
+        StatusCode::Ok,
+        Headers::new(),
+        "",
+    );
+
+    let response = http_client
+        .execute_request(&request(Method::Get, "/a?comp=tags"))
+        .await?;
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert!(http_client
+        .execute_request(&request(Method::Get, "/a?comp=metadata"))
+        .await
+        .is_err());
+    assert!(http_client
+        .execute_request(&request(Method::Get, "/a"))
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_match_is_a_mock_framework_error() {
+    let http_client =
+        MockHttpClient::new().with_response(Method::Get, "/a", StatusCode::Ok, Headers::new(), "");
+
+    let err = http_client
+        .execute_request(&request(Method::Put, "/a"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::MockFramework);
+    assert!(err.to_string().contains("PUT /a"));
+    assert_eq!(http_client.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn blob_exists_with_canned_response() -> azure_core::Result<()> {
+    let http_client = MockHttpClient::new().with_response(
+        Method::Head,
+        "/container/missing",
+        StatusCode::NotFound,
+        Headers::new(),
+        "",
+    );
+    let blob = client(http_client)
+        .container_client("container")
+        .blob_client("missing");
+
+    assert!(!blob.exists().await?);
+    Ok(())
+}
+
+fn error_headers(error_code: &'static str) -> Headers {
+    let mut headers = Headers::new();
+    headers.insert(headers::ERROR_CODE, error_code);
+    headers
+}
+
+#[tokio::test]
+async fn create_if_not_exists_with_canned_response() -> azure_core::Result<()> {
+    let http_client = MockHttpClient::new().with_response(
+        Method::Put,
+        "/existing",
+        StatusCode::Conflict,
+        error_headers("ContainerAlreadyExists"),
+        "",
+    );
+    let container = client(http_client).container_client("existing");
+    assert!(!container.create_if_not_exists().await?);
+
+    let http_client = MockHttpClient::new().with_response(
+        Method::Put,
+        "/deleting",
+        StatusCode::Conflict,
+        error_headers("ContainerBeingDeleted"),
+        "",
+    );
+    let err = client(http_client)
+        .container_client("deleting")
+        .create_if_not_exists()
+        .await
+        .expect_err("a container being deleted is not an existing container");
+    assert!(azure_storage::error::is_conflict(&err));
+    assert!(!azure_storage_blobs::container::is_container_already_exists(&err));
+    Ok(())
+}
+
+fn blob_headers() -> Headers {
+    let mut headers = Headers::new();
+    headers.insert(headers::REQUEST_ID, "00000000-0000-0000-0000-000000000000");
+    headers.insert(headers::DATE, "Thu, 01 Jan 2015 00:00:00 GMT");
+    headers.insert(headers::CREATION_TIME, "Thu, 01 Jan 2015 00:00:00 GMT");
+    headers.insert(headers::LAST_MODIFIED, "Thu, 01 Jan 2015 00:00:00 GMT");
+    headers.insert(headers::ETAG, "\"0x8D0\"");
+    headers.insert(headers::BLOB_TYPE, "BlockBlob");
+    headers.insert(headers::SERVER_ENCRYPTED, "true");
+    headers
+}
+
+#[tokio::test]
+async fn get_properties_prefer_get_reports_whole_blob() -> azure_core::Result<()> {
+    let mut headers = blob_headers();
+    headers.insert(headers::CONTENT_LENGTH, "1");
+    headers.insert(headers::CONTENT_RANGE, "bytes 0-0/1024");
+    headers.insert(BLOB_CONTENT_MD5, "CY9rzUYh03PK3k6DJie09g==");
+    let http_client = MockHttpClient::new().with_response(
+        Method::Get,
+        "/container/blob",
+        StatusCode::PartialContent,
+        headers,
+        "x",
+    );
+    let blob = client(http_client)
+        .container_client("container")
+        .blob_client("blob");
+
+    let properties = blob
+        .get_properties()
+        .prefer_get(true)
+        .await?
+        .blob
+        .properties;
+    assert_eq!(properties.content_length, 1024);
+    assert_eq!(
+        properties.content_md5.map(|md5| *md5.as_slice()),
+        Some(*b"\x09\x8f\x6b\xcd\x46\x21\xd3\x73\xca\xde\x4e\x83\x26\x27\xb4\xf6")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_range_reports_blob_size() -> azure_core::Result<()> {
+    let mut headers = blob_headers();
+    headers.insert(headers::CONTENT_LENGTH, "3");
+    headers.insert(headers::CONTENT_RANGE, "bytes 2-4/10");
+    let http_client = MockHttpClient::new().with_response(
+        Method::Get,
+        "/container/blob",
+        StatusCode::PartialContent,
+        headers,
+        "234",
+    );
+    let blob = client(http_client)
+        .container_client("container")
+        .blob_client("blob");
+
+    let response = blob
+        .get()
+        .range(2u64..5u64)
+        .into_stream()
+        .next()
+        .await
+        .expect("stream should return a response")?;
+    assert_eq!(response.blob_size, Some(10));
+    assert_eq!(&response.data.collect().await?[..], b"234");
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_request_through_pipeline() -> azure_core::Result<()> {
+    let http_client = MockHttpClient::new().with_response(
+        Method::Get,
+        "/container/blob?comp=tags",
+        StatusCode::Ok,
+        Headers::new(),
+        "<Tags><TagSet/></Tags>",
+    );
+    let blob = client(http_client)
+        .container_client("container")
+        .blob_client("blob");
+
+    let mut url = blob.url()?;
+    url.query_pairs_mut().append_pair("comp", "tags");
+    let mut request = Request::new(url, Method::Get);
+    let response = blob.send_request(&mut Context::new(), &mut request).await?;
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert!(request
+        .headers()
+        .get_optional_str(&headers::VERSION)
+        .is_some());
+    assert!(request
+        .headers()
+        .get_optional_str(&headers::MS_DATE)
+        .is_some());
+    assert!(request
+        .headers()
+        .get_optional_str(&headers::CONTENT_LENGTH)
+        .is_none());
+    Ok(())
+}
+
+// A 10 byte blob whose first download is cut after 4 bytes.
+fn interrupted_blob() -> (Arc<MockHttpClient>, BlobClient) {
+    let mut headers = blob_headers();
+    headers.insert(headers::CONTENT_LENGTH, "10");
+    headers.insert(headers::CONTENT_RANGE, "bytes 0-9/10");
+    let mut rest_headers = blob_headers();
+    rest_headers.insert(headers::CONTENT_LENGTH, "6");
+    rest_headers.insert(headers::CONTENT_RANGE, "bytes 4-9/10");
+    let http_client = Arc::new(
+        MockHttpClient::new()
+            .with_interrupted_response(
+                Method::Get,
+                "/container/blob",
+                StatusCode::PartialContent,
+                headers,
+                "0123456789",
+                4,
+            )
+            .with_response(
+                Method::Get,
+                "/container/blob",
+                StatusCode::PartialContent,
+                rest_headers,
+                "456789",
+            ),
+    );
+    let blob = shared_client(http_client.clone())
+        .container_client("container")
+        .blob_client("blob");
+    (http_client, blob)
+}
+
+fn assert_resumed_at_offset(http_client: &MockHttpClient) {
+    let requests = http_client.requests();
+    assert_eq!(requests.len(), 2);
+    let resumed = requests[1].headers();
+    assert_eq!(
+        resumed.get_optional_str(&headers::MS_RANGE),
+        Some("bytes=4-9")
+    );
+    assert_eq!(
+        resumed.get_optional_str(&headers::IF_MATCH),
+        Some("\"0x8D0\"")
+    );
+}
+
+#[tokio::test]
+async fn get_blob_resumes_interrupted_body() -> azure_core::Result<()> {
+    let (http_client, blob) = interrupted_blob();
+
+    let mut data = Vec::new();
+    let mut stream = blob.get().resume_on_failure(true).into_stream();
+    while let Some(response) = stream.next().await {
+        let mut body = response?.data;
+        while let Some(chunk) = body.next().await {
+            data.extend(&chunk?);
+        }
+    }
+    assert_eq!(data, b"0123456789");
+    assert_resumed_at_offset(&http_client);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_blob_without_resume_fails_on_interrupted_body() -> azure_core::Result<()> {
+    let (http_client, blob) = interrupted_blob();
+
+    let response = blob.get().into_stream().next().await.unwrap()?;
+    assert!(response.data.collect().await.is_err());
+    assert_eq!(http_client.requests().len(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "tokio-fs")]
+#[tokio::test]
+async fn download_to_file_resumes_interrupted_body() -> azure_core::Result<()> {
+    let (http_client, blob) = interrupted_blob();
+    let path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+
+    assert!(blob
+        .get()
+        .range(0u64..10)
+        .download_to_file(&path)
+        .await
+        .is_err());
+
+    let written = blob
+        .get()
+        .resume_on_failure(true)
+        .download_to_file(&path)
+        .await?;
+    assert_eq!(written, 10);
+    assert_eq!(tokio::fs::read(&path).await?, b"0123456789");
+    tokio::fs::remove_file(&path).await?;
+    assert_resumed_at_offset(&http_client);
+    Ok(())
+}
+
+// Serve `data` as the whole of blob `/container/{name}`.
+fn blob_download(name: &str, data: &'static [u8], mut headers: Headers) -> MockHttpClient {
+    headers.insert(headers::CONTENT_LENGTH, data.len().to_string());
+    headers.insert(
+        headers::CONTENT_RANGE,
+        format!("bytes 0-{}/{}", data.len() - 1, data.len()),
+    );
+    MockHttpClient::new().with_response(
+        Method::Get,
+        format!("/container/{name}"),
+        StatusCode::PartialContent,
+        headers,
+        data,
+    )
+}
+
+#[tokio::test]
+async fn get_content_with_properties() -> azure_core::Result<()> {
+    let mut headers = blob_headers();
+    headers.insert(headers::CONTENT_TYPE, "text/plain");
+    let blob = client(blob_download("props.txt", b"hello world", headers))
+        .container_client("container")
+        .blob_client("props.txt");
+
+    let (data, properties) = blob.get_content_with_properties().await?;
+    assert_eq!(&data[..], b"hello world");
+    assert_eq!(properties.properties.content_type, "text/plain");
+    assert_eq!(properties.properties.content_length, 11);
+    assert_eq!(properties.properties.etag.to_string(), "\"0x8D0\"");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_content_with_properties_chunked() -> azure_core::Result<()> {
+    // a chunked response carries neither Content-Length nor Content-Range
+    let http_client = MockHttpClient::new().with_response(
+        Method::Get,
+        "/container/chunked.txt",
+        StatusCode::Ok,
+        blob_headers(),
+        "hello world",
+    );
+    let blob = client(http_client)
+        .container_client("container")
+        .blob_client("chunked.txt");
+
+    let response = blob
+        .get()
+        .into_stream()
+        .next()
+        .await
+        .expect("stream should return a response")?;
+    assert_eq!(response.blob_size, None);
+    assert_eq!(&response.data.collect().await?[..], b"hello world");
+
+    let (data, properties) = blob.get_content_with_properties().await?;
+    assert_eq!(&data[..], b"hello world");
+    assert_eq!(properties.properties.content_length, 11);
+    Ok(())
+}
+
+#[cfg(feature = "decompress")]
+#[tokio::test]
+async fn get_content_decompressed() -> azure_core::Result<()> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(b"hello, compressed world")?;
+    let compressed: &'static [u8] = encoder.finish()?.leak();
+
+    let mut headers = blob_headers();
+    headers.insert(headers::CONTENT_ENCODING, "gzip");
+    let blob = client(blob_download("compressed.txt", compressed, headers))
+        .container_client("container")
+        .blob_client("compressed.txt");
+
+    assert_eq!(
+        blob.get_content_decompressed().await?,
+        b"hello, compressed world"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "tokio-fs")]
+#[tokio::test]
+async fn download_to_file() -> azure_core::Result<()> {
+    let blob = client(blob_download("download.txt", b"downloaded", blob_headers()))
+        .container_client("container")
+        .blob_client("download.txt");
+
+    let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let path = dir.join("nested").join("download.txt");
+    let written = blob.download_to_file(&path).await?;
+    assert_eq!(written, 10);
+    assert_eq!(tokio::fs::read(&path).await?, b"downloaded");
+    tokio::fs::remove_dir_all(&dir).await?;
+    Ok(())
+}
+
+#[cfg(feature = "tokio-fs")]
+#[tokio::test]
+async fn upload_file() -> azure_core::Result<()> {
+    use azure_storage_blobs::blob::operations::UploadFileResponse;
+
+    let mut headers = blob_headers();
+    headers.insert(headers::REQUEST_SERVER_ENCRYPTED, "true");
+    let http_client = Arc::new(MockHttpClient::new().with_response(
+        Method::Put,
+        "/container/upload.json",
+        StatusCode::Created,
+        headers,
+        "",
+    ));
+    let blob = shared_client(http_client.clone())
+        .container_client("container")
+        .blob_client("upload.json");
+
+    let path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+    tokio::fs::write(&path, b"{\"a\": 1}").await?;
+    let response = blob.upload_file(&path).await;
+    tokio::fs::remove_file(&path).await?;
+    assert!(matches!(response?, UploadFileResponse::BlockBlob(_)));
+
+    let requests = http_client.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0]
+            .headers()
+            .get_optional_str(&headers::HeaderName::from_static("x-ms-blob-content-type")),
+        Some("application/json")
+    );
+    assert_eq!(
+        requests[0].headers().get_optional_str(&headers::BLOB_TYPE),
+        Some("BlockBlob")
+    );
+    Ok(())
+}
@@ -1,5 +1,5 @@
 #![cfg(all(test, feature = "test_e2e"))]
-use azure_core::prelude::*;
+use azure_core::{prelude::*, AppendToUrlQuery};
 use azure_storage::prelude::*;
 use azure_storage_blobs::prelude::*;
 use futures::StreamExt;
@@ -45,6 +45,58 @@ async fn put_page_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn resize_page_blob() -> azure_core::Result<()> {
+    let container_name = format!("resize-{}", uuid::Uuid::new_v4());
+    let blob_service = initialize();
+    let container = blob_service.container_client(&container_name);
+    let blob = container.blob_client("disk.vhd");
+
+    container.create().await?;
+    blob.put_page_blob(1024).await?;
+
+    blob.resize_page_blob(4096).await?;
+    let properties = blob.get_properties().await?.blob.properties;
+    assert_eq!(properties.content_length, 4096);
+
+    assert!(blob.resize_page_blob(1000).await.is_err());
+
+    container.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn incremental_copy() -> azure_core::Result<()> {
+    let container_name = format!("incremental-{}", uuid::Uuid::new_v4());
+    let blob_service = initialize();
+    let container = blob_service.container_client(&container_name);
+    let source = container.blob_client("source.vhd");
+    let destination = container.blob_client("backup.vhd");
+
+    container.create().await?;
+    source.put_page_blob(1024).await?;
+    let snapshot = source.snapshot().await?.snapshot;
+
+    let expiry = time::OffsetDateTime::now_utc() + time::Duration::hours(1);
+    let sas = source
+        .shared_access_signature(
+            BlobSasPermissions {
+                read: true,
+                ..Default::default()
+            },
+            expiry,
+        )
+        .await?;
+    let mut source_url = source.generate_signed_blob_url(&sas)?;
+    snapshot.append_to_url_query(&mut source_url);
+
+    destination.incremental_copy(source_url).await?;
+    assert!(destination.exists().await?);
+
+    container.delete().await?;
+    Ok(())
+}
+
 fn initialize() -> BlobServiceClient {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");